@@ -2,7 +2,9 @@
 
 use crate::{Action, Button, ButtonVariant, Icon};
 
+use std::rc::Rc;
 use yew::prelude::*;
+use yew::virtual_dom::VChild;
 
 #[deprecated(since = "0.4.0", note = "This type has been renamed to 'AlertType'")]
 pub type Type = AlertType;
@@ -54,8 +56,14 @@ impl AlertType {
 pub struct AlertProperties {
     #[prop_or_default]
     pub id: String,
+    /// Additional classes
+    #[prop_or_default]
+    pub class: Classes,
     #[prop_or_default]
     pub r#type: AlertType,
+    /// An icon replacing the icon of the type, e.g. a spinner for a task in progress.
+    #[prop_or_default]
+    pub icon: Option<Icon>,
     pub title: String,
     #[prop_or_default]
     pub children: Children,
@@ -63,6 +71,9 @@ pub struct AlertProperties {
     pub inline: bool,
     #[prop_or_default]
     pub truncate: bool,
+    /// Only show the description once the user expands the alert, using its toggle.
+    #[prop_or_default]
+    pub expandable: bool,
     #[prop_or_default]
     pub actions: Vec<Action>,
     #[prop_or_default]
@@ -80,9 +91,16 @@ pub struct AlertProperties {
 /// Defined by [`AlertProperties`].
 #[function_component(Alert)]
 pub fn alert(props: &AlertProperties) -> Html {
+    let expanded = use_state_eq(|| false);
+    let ontoggle = {
+        let expanded = expanded.clone();
+        Callback::from(move |_| expanded.set(!*expanded))
+    };
+
     let mut classes = classes!("pf-c-alert");
 
     classes.extend(props.r#type.as_classes());
+    classes.extend(props.class.clone());
 
     if props.inline {
         classes.push("pf-m-inline");
@@ -92,6 +110,13 @@ pub fn alert(props: &AlertProperties) -> Html {
         classes.push("pf-m-truncate");
     }
 
+    if props.expandable {
+        classes.push("pf-m-expandable");
+        if *expanded {
+            classes.push("pf-m-expanded");
+        }
+    }
+
     let t = props.r#type;
 
     let actions = if props.actions.is_empty() {
@@ -102,7 +127,7 @@ pub fn alert(props: &AlertProperties) -> Html {
                 {for props.actions.iter().map(|action|{
                     html!{
                         <Button
-                            variant={ButtonVariant::InlineLink}
+                            variant={action.variant.unwrap_or(ButtonVariant::InlineLink)}
                             label={action.label.clone()}
                             onclick={action.callback.reform(|_|())}
                         />
@@ -113,14 +138,10 @@ pub fn alert(props: &AlertProperties) -> Html {
     };
 
     html! (
-        <div id={props.id.clone()} class={classes} aria_label={t.aria_label()}>
-            <div class="pf-c-alert__icon">{ t.icon() }</div>
-            <div class="pf-c-alert__title">
-                <strong>
-                    <span class="pf-screen-reader">{ t.aria_label() }{":"}</span>
-                    { &props.title }
-                </strong>
-            </div>
+        <div id={props.id.clone()} class={classes} aria-label={t.aria_label()}>
+            { render_toggle(props, *expanded, ontoggle) }
+            <div class="pf-c-alert__icon">{ props.icon.unwrap_or(t.icon()) }</div>
+            { render_title(props) }
 
 
             if let Some(onclose) = props.onclose.as_ref() {
@@ -130,7 +151,7 @@ pub fn alert(props: &AlertProperties) -> Html {
             }
 
 
-            if !props.children.is_empty() {
+            if !props.children.is_empty() && (!props.expandable || *expanded) {
                 <div class="pf-c-alert__description">
                     { for props.children.iter() }
                 </div>
@@ -142,32 +163,284 @@ pub fn alert(props: &AlertProperties) -> Html {
     )
 }
 
+/// Render the title, prefixed with the type for screen readers, as the type is only shown visually
+/// otherwise.
+pub(crate) fn render_title(props: &AlertProperties) -> Html {
+    html!(
+        <div class="pf-c-alert__title">
+            <strong>
+                <span class="pf-screen-reader">{ props.r#type.aria_label() }{":"}</span>
+                { &props.title }
+            </strong>
+        </div>
+    )
+}
+
+/// Render the toggle of an expandable alert.
+fn render_toggle(props: &AlertProperties, expanded: bool, ontoggle: Callback<()>) -> Html {
+    if !props.expandable {
+        return html!();
+    }
+
+    html!(
+        <div class="pf-c-alert__toggle">
+            <button
+                class="pf-c-button pf-m-plain"
+                type="button"
+                aria-expanded={expanded.to_string()}
+                aria-label={format!("Toggle {}", props.r#type.aria_label())}
+                onclick={ontoggle.reform(|_| ())}
+            >
+                <span class="pf-c-alert__toggle-icon">{ Icon::AngleRight }</span>
+            </button>
+        </div>
+    )
+}
+
 // alert group
 
-/// A group for [`Alert`]s
+/// Properties for [`AlertGroup`]
 #[derive(Clone, PartialEq, Properties)]
 pub struct GroupProperties {
     #[prop_or_default]
     pub children: ChildrenWithProps<Alert>,
+    /// Show the alerts as toasts, in a corner of the page.
+    ///
+    /// This is used by the [`ToastViewer`](crate::prelude::ToastViewer).
     #[prop_or_default]
     pub toast: bool,
+    /// Render all alerts of the group as inline alerts.
+    #[prop_or_default]
+    pub inline: bool,
+    /// Additional styles
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+    #[prop_or_default]
+    pub onmouseenter: Callback<MouseEvent>,
+    #[prop_or_default]
+    pub onmouseleave: Callback<MouseEvent>,
+    /// The message of the overflow button, shown after all alerts.
+    ///
+    /// The button is only shown when a message is set.
+    #[prop_or_default]
+    pub overflow: Option<String>,
+    /// Called when the user clicks on the overflow button.
+    #[prop_or_default]
+    pub onoverflow: Callback<()>,
+    /// Announce added alerts to screen readers, using this politeness (e.g. `polite`).
+    #[prop_or_default]
+    pub aria_live: Option<AttrValue>,
 }
 
+/// A group for [`Alert`]s
+///
+/// Without being a toast group, this shows a static stack of alerts, e.g. at the top of the
+/// content of a page.
+///
+/// ## Properties
+///
+/// Defined by [`GroupProperties`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///     let alerts = [
+///         (AlertType::Danger, "The server is unreachable"),
+///         (AlertType::Warning, "The certificate expires soon"),
+///     ];
+///
+///     html!(
+///         <AlertGroup inline=true>
+///             { for alerts.into_iter().map(|(r#type, title)| html_nested!(
+///                 <Alert {r#type} title={title.to_string()} />
+///             )) }
+///         </AlertGroup>
+///     )
+/// }
+/// ```
 #[function_component(AlertGroup)]
 pub fn view(props: &GroupProperties) -> Html {
+    html! (
+        <ul
+            class={group_classes(props)}
+            style={props.style.clone()}
+            onmouseenter={props.onmouseenter.clone()}
+            onmouseleave={props.onmouseleave.clone()}
+            aria-live={props.aria_live.clone()}
+        >
+            { render_items(props) }
+            if let Some(overflow) = &props.overflow {
+                <li class="pf-c-alert-group__item">
+                    <button
+                        class="pf-c-alert-group__overflow-button"
+                        onclick={props.onoverflow.reform(|_| ())}
+                    >
+                        { overflow }
+                    </button>
+                </li>
+            }
+        </ul>
+    )
+}
+
+fn group_classes(props: &GroupProperties) -> Classes {
     let mut classes = classes!("pf-c-alert-group");
 
     if props.toast {
         classes.push(classes!("pf-m-toast"));
     }
 
-    html! (
-        <ul class={classes}>
-            { for props.children.iter().map(|child|html!{
+    classes
+}
+
+/// The alerts of the group, made inline if requested by the group.
+fn group_alerts(props: &GroupProperties) -> impl Iterator<Item = VChild<Alert>> + '_ {
+    props.children.iter().map(|mut alert| {
+        if props.inline {
+            Rc::make_mut(&mut alert.props).inline = true;
+        }
+        alert
+    })
+}
+
+/// The items of the group, keyed by the keys of their alerts.
+///
+/// The items form their own list, so that the alerts can be reordered by their keys, independent
+/// of the overflow item.
+fn render_items(props: &GroupProperties) -> Html {
+    group_alerts(props)
+        .map(|alert| {
+            let alert = Html::from(alert);
+            let key = alert.key().cloned();
+
+            let mut item = html!(
                 <li class="pf-c-alert-group__item">
-                    { child }
+                    { alert }
                 </li>
-            })}
-        </ul>
-    )
+            );
+            if let Html::VTag(tag) = &mut item {
+                tag.key = key;
+            }
+            item
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use yew::virtual_dom::VTag;
+
+    #[test]
+    fn test_toggle() {
+        let props = yew::props!(AlertProperties {
+            title: "Title".to_string()
+        });
+        assert_eq!(render_toggle(&props, false, Callback::noop()), html!());
+
+        let props = yew::props!(AlertProperties {
+            title: "Title".to_string(),
+            expandable: true,
+        });
+        match render_toggle(&props, true, Callback::noop()) {
+            Html::VTag(tag) => {
+                assert_eq!(
+                    tag.attributes.iter().next(),
+                    Some(("class", "pf-c-alert__toggle"))
+                );
+                match tag.children().first() {
+                    Some(Html::VTag(button)) => assert_eq!(expanded(button), Some("true")),
+                    other => panic!("expected the toggle button: {other:?}"),
+                }
+            }
+            other => panic!("expected the toggle: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_title() {
+        for (r#type, prefix) in [
+            (AlertType::Default, "Default alert"),
+            (AlertType::Info, "Information alert"),
+            (AlertType::Success, "Success alert"),
+            (AlertType::Warning, "Warning alert"),
+            (AlertType::Danger, "Danger alert"),
+        ] {
+            let props = yew::props!(AlertProperties {
+                r#type,
+                title: "Title".to_string(),
+            });
+
+            let title = match render_title(&props) {
+                Html::VTag(tag) => tag,
+                other => panic!("expected the title: {other:?}"),
+            };
+            let strong = match title.children().first() {
+                Some(Html::VTag(tag)) => tag.clone(),
+                other => panic!("expected the text: {other:?}"),
+            };
+            assert_eq!(
+                strong.children().first(),
+                Some(&html!(<span class="pf-screen-reader">{ prefix }{":"}</span>))
+            );
+        }
+    }
+
+    #[test]
+    fn test_group() {
+        let alerts = || {
+            ChildrenWithProps::new(vec![
+                html_nested!(<Alert title="First" />),
+                html_nested!(<Alert title="Second" />),
+            ])
+        };
+
+        let props = yew::props!(GroupProperties {
+            children: alerts(),
+            toast: true,
+        });
+        assert_eq!(
+            group_classes(&props).to_string(),
+            "pf-c-alert-group pf-m-toast"
+        );
+        assert!(group_alerts(&props).all(|alert| !alert.props.inline));
+
+        let props = yew::props!(GroupProperties {
+            children: alerts(),
+            inline: true,
+        });
+        assert_eq!(group_classes(&props).to_string(), "pf-c-alert-group");
+        assert!(group_alerts(&props).all(|alert| alert.props.inline));
+    }
+
+    #[test]
+    fn test_item_keys() {
+        let props = yew::props!(GroupProperties {
+            children: ChildrenWithProps::new(vec![
+                html_nested!(<Alert key="a" title="First" />),
+                html_nested!(<Alert key="b" title="Second" />),
+            ]),
+        });
+
+        let items = match render_items(&props) {
+            Html::VList(items) => items,
+            other => panic!("expected a list of items: {other:?}"),
+        };
+        let keys: Vec<_> = items
+            .iter()
+            .map(|item| item.key().map(ToString::to_string))
+            .collect();
+        assert_eq!(keys, vec![Some("a".into()), Some("b".into())]);
+    }
+
+    fn expanded(tag: &VTag) -> Option<&str> {
+        tag.attributes
+            .iter()
+            .find_map(|(name, value)| (name == "aria-expanded").then_some(value))
+    }
 }
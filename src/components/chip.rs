@@ -16,6 +16,17 @@ pub struct ChipProperties {
     pub draggable: bool,
     #[prop_or_default]
     pub onclose: Option<Callback<()>>,
+    /// Called when clicking the chip, rendering it as a button.
+    ///
+    /// A clickable chip is not closable, as the close button would be nested in the button of the
+    /// chip. This takes precedence over [`Self::onclose`].
+    #[prop_or_default]
+    pub onclick: Option<Callback<()>>,
+    /// Link the chip to a target, rendering it as an anchor.
+    ///
+    /// Like a clickable chip, a linked chip is not closable.
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
     #[prop_or_default]
     pub icon: Option<Icon>,
 }
@@ -29,6 +40,30 @@ pub struct ChipProperties {
 /// ## Properties
 ///
 /// Defined by [`ChipProperties`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///     let active = use_state_eq(|| false);
+///     let onclick = {
+///         let active = active.clone();
+///         Callback::from(move |()| active.set(!*active))
+///     };
+///
+///     html!(
+///         <Chip
+///             text="Active"
+///             icon={active.then_some(Icon::Check)}
+///             {onclick}
+///         />
+///     )
+/// }
+/// ```
 #[function_component(Chip)]
 pub fn chip(props: &ChipProperties) -> Html {
     let mut classes = Classes::from("pf-c-chip");
@@ -51,10 +86,28 @@ pub fn chip(props: &ChipProperties) -> Html {
         </>
     };
 
-    if props.overflow {
-        html! {<button class={classes}>{body}</button>}
+    render_chip(props, classes, body)
+}
+
+/// Check if the chip is rendered as an interactive element, which must not contain the close
+/// button.
+fn is_interactive(props: &ChipProperties) -> bool {
+    props.href.is_some() || props.overflow || props.onclick.is_some()
+}
+
+/// Render the element of the chip, depending on it being a link, a button, or passive.
+fn render_chip(props: &ChipProperties, class: Classes, body: Html) -> Html {
+    let onclick = props
+        .onclick
+        .as_ref()
+        .map(|onclick| onclick.reform(|_: MouseEvent| ()));
+
+    if let Some(href) = &props.href {
+        html! {<a {class} href={href.clone()} {onclick}>{body}</a>}
+    } else if is_interactive(props) {
+        html! {<button {class} type="button" {onclick}>{body}</button>}
     } else {
-        html! {<div class={classes}>{body}</div>}
+        html! {<div {class}>{body}</div>}
     }
 }
 
@@ -75,6 +128,10 @@ fn render_badge(props: &ChipProperties) -> Html {
 }
 
 fn render_close(props: &ChipProperties) -> Html {
+    if is_interactive(props) {
+        return html!();
+    }
+
     html! (
         if let Some(onclose) = &props.onclose {
             <Button variant={ButtonVariant::Plain} icon={Icon::Times} onclick={onclose.reform(|e: MouseEvent| {
@@ -84,3 +141,94 @@ fn render_close(props: &ChipProperties) -> Html {
         }
     )
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use yew::virtual_dom::VTag;
+
+    fn element(props: &ChipProperties) -> Box<VTag> {
+        match render_chip(props, classes!("pf-c-chip"), html!()) {
+            Html::VTag(tag) => tag,
+            other => panic!("expected an element: {other:?}"),
+        }
+    }
+
+    /// Check if the element has an event listener, as yew doesn't expose them.
+    ///
+    /// Elements with a single listener attribute keep an empty slot, in case it wasn't set.
+    fn has_listener(tag: &VTag) -> bool {
+        [Box::new([]) as Box<[_]>, Box::new([None])]
+            .into_iter()
+            .all(|listeners| {
+                let mut passive = tag.clone();
+                passive.set_listeners(listeners);
+                *tag != passive
+            })
+    }
+
+    fn attribute<'a>(tag: &'a VTag, name: &str) -> Option<&'a str> {
+        tag.attributes
+            .iter()
+            .find_map(|(key, value)| (key == name).then_some(value))
+    }
+
+    #[test]
+    fn test_passive() {
+        let tag = element(&yew::props!(ChipProperties {}));
+        assert_eq!(tag.tag(), "div");
+        assert_eq!(attribute(&tag, "type"), None);
+        assert!(!has_listener(&tag));
+    }
+
+    #[test]
+    fn test_clickable() {
+        let props = yew::props!(ChipProperties {
+            onclick: Callback::noop(),
+        });
+
+        // a button, for being accessible by keyboard
+        let tag = element(&props);
+        assert_eq!(tag.tag(), "button");
+        assert_eq!(attribute(&tag, "type"), Some("button"));
+        assert!(has_listener(&tag));
+
+        // an overflow chip without a callback has nothing to listen to
+        let tag = element(&yew::props!(ChipProperties { overflow: true }));
+        assert_eq!(tag.tag(), "button");
+        assert!(!has_listener(&tag));
+    }
+
+    #[test]
+    fn test_clickable_close() {
+        let is_empty = |props: &ChipProperties| match render_close(props) {
+            Html::VList(list) => list.is_empty(),
+            _ => false,
+        };
+
+        let mut props = yew::props!(ChipProperties {
+            onclose: Callback::noop(),
+        });
+        assert!(!is_empty(&props));
+
+        // the close button must not be nested in the button of the chip
+        props.onclick = Some(Callback::noop());
+        assert!(is_empty(&props));
+
+        props.onclick = None;
+        props.href = Some("#filter".into());
+        assert!(is_empty(&props));
+    }
+
+    #[test]
+    fn test_link() {
+        let props = yew::props!(ChipProperties {
+            href: "#filter",
+            onclick: Callback::noop(),
+        });
+
+        let tag = element(&props);
+        assert_eq!(tag.tag(), "a");
+        assert_eq!(attribute(&tag, "href"), Some("#filter"));
+    }
+}
@@ -1,7 +1,23 @@
 //! Chip Group
 
-use crate::{use_prop_id, Chip};
+use crate::{
+    use_prop_id, Button, ButtonVariant, Chip, Dropdown, DropdownChildVariant, DropdownItemText,
+    Icon,
+};
+use std::rc::Rc;
+use yew::html::ChildrenRenderer;
 use yew::prelude::*;
+use yew::virtual_dom::VChild;
+
+/// How a collapsible [`ChipGroup`] shows its hidden chips.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ChipOverflowMode {
+    /// Expand the group inline, showing all chips.
+    #[default]
+    Expand,
+    /// Show the hidden chips in a dropdown, keeping the group collapsed.
+    Popover,
+}
 
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct ChipGroupProperties {
@@ -16,16 +32,56 @@ pub struct ChipGroupProperties {
 
     #[prop_or("Chip group list".into())]
     pub aria_label: AttrValue,
+
+    /// Only show the first [`Self::num_chips`] chips, followed by an overflow chip to show the
+    /// others.
+    #[prop_or_default]
+    pub collapsible: bool,
+
+    /// The number of chips shown when collapsed.
+    #[prop_or(3)]
+    pub num_chips: usize,
+
+    /// How the hidden chips are shown, when clicking the overflow chip.
+    #[prop_or_default]
+    pub overflow_mode: ChipOverflowMode,
+
+    /// Called when the user toggles the overflow chip, with the new expanded state.
+    ///
+    /// This is not called for the [`ChipOverflowMode::Popover`] mode, as the group stays collapsed.
+    #[prop_or_default]
+    pub onoverflow_toggle: Callback<bool>,
+
+    /// Called when the user clicks a chip, with the index of the chip.
+    ///
+    /// This makes the chips clickable, overriding the click callbacks of the chips themselves.
+    /// Clickable chips can't be closed, so this takes precedence over [`Self::onchipclose`].
+    #[prop_or_default]
+    pub onchipclick: Option<Callback<usize>>,
+
+    /// Called when the user closes a chip, with the index of the chip.
+    ///
+    /// This overrides the close callbacks of the chips themselves.
+    #[prop_or_default]
+    pub onchipclose: Option<Callback<usize>>,
+
+    /// Don't allow closing any of the chips, removing their close buttons.
+    ///
+    /// This takes precedence over [`Self::onchipclose`].
+    #[prop_or_default]
+    pub read_only: bool,
+
+    /// Called when the user closes the category.
+    ///
+    /// The close button is only shown for a category, which requires a [`Self::label`].
+    #[prop_or_default]
+    pub onclose: Option<Callback<()>>,
 }
 
 #[function_component(ChipGroup)]
 pub fn chip_group(props: &ChipGroupProperties) -> Html {
     let id = use_prop_id(props.id.clone());
-
-    let (aria_label, aria_labeled_by) = match props.label.is_some() {
-        true => (AttrValue::default(), Some(id.to_string())),
-        false => (props.aria_label.clone(), None),
-    };
+    let expanded = use_state_eq(|| false);
 
     let mut class = classes!("pf-c-chip-group");
 
@@ -33,6 +89,16 @@ pub fn chip_group(props: &ChipGroupProperties) -> Html {
         class.push(classes!("pf-m-category"));
     }
 
+    let ontoggle = {
+        let expanded = expanded.clone();
+        let onoverflow_toggle = props.onoverflow_toggle.clone();
+        Callback::from(move |()| {
+            let next = !*expanded;
+            expanded.set(next);
+            onoverflow_toggle.emit(next);
+        })
+    };
+
     html! (
         <div {class}>
             <div class="pf-c-chip-group__main">
@@ -45,21 +111,381 @@ pub fn chip_group(props: &ChipGroupProperties) -> Html {
                         { &label }
                     </span>
                 }
-                <ul
-                    class="pf-c-chip-group__list"
-                    role="list"
-                    aria-label={aria_label}
-                    aria-labeledby={aria_labeled_by}
-                >
-                    { for props.children.iter().map(|chip| {
-                        html!(
-                            <li class="pf-c-chip-group__list-item">
-                                { chip }
-                            </li>
-                        )
-                    })}
-                </ul>
+                { render_list(props, &id, *expanded, ontoggle) }
             </div>
+            { render_close(props, &id) }
         </div>
     )
 }
+
+fn render_close(props: &ChipGroupProperties, id: &str) -> Html {
+    match (&props.label, &props.onclose) {
+        (Some(_), Some(onclose)) => html!(
+            <div class="pf-c-chip-group__close">
+                <Button
+                    variant={ButtonVariant::Plain}
+                    icon={Icon::TimesCircle}
+                    id={format!("{id}-close")}
+                    aria_label="Close chip group"
+                    aria_labelledby={format!("{id}-close {id}-label")}
+                    onclick={onclose.reform(|_| ())}
+                />
+            </div>
+        ),
+        _ => html!(),
+    }
+}
+
+fn render_list(
+    props: &ChipGroupProperties,
+    id: &str,
+    expanded: bool,
+    ontoggle: Callback<()>,
+) -> Html {
+    // a category is labelled by its label element
+    let (aria_label, aria_labelledby) = match props.label.is_some() {
+        true => (None, Some(format!("{id}-label"))),
+        false => (Some(props.aria_label.clone()), None),
+    };
+
+    html!(
+        <ul
+            class="pf-c-chip-group__list"
+            role="list"
+            aria-label={aria_label}
+            aria-labelledby={aria_labelledby}
+        >
+            { render_items(props, expanded, ontoggle) }
+        </ul>
+    )
+}
+
+/// The overflow chip of a collapsible group.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Overflow {
+    /// Show the number of hidden chips.
+    More(usize),
+    /// Collapse the expanded group.
+    Less,
+}
+
+/// Evaluate the number of chips to show, and the overflow chip, if any.
+fn overflow(props: &ChipGroupProperties, expanded: bool) -> (usize, Option<Overflow>) {
+    let len = props.children.len();
+
+    if !props.collapsible || len <= props.num_chips {
+        (len, None)
+    } else if expanded && props.overflow_mode == ChipOverflowMode::Expand {
+        (len, Some(Overflow::Less))
+    } else {
+        (props.num_chips, Some(Overflow::More(len - props.num_chips)))
+    }
+}
+
+/// Render the items of the list, all of them keyed, as yew only diffs fully keyed lists by key.
+fn render_items(props: &ChipGroupProperties, expanded: bool, ontoggle: Callback<()>) -> Html {
+    let (count, overflow) = overflow(props, expanded);
+
+    let mut items: Vec<Html> = chips(props)
+        .take(count)
+        .enumerate()
+        .map(|(index, chip)| {
+            html!(
+                <li class="pf-c-chip-group__list-item" key={index}>
+                    { chip }
+                </li>
+            )
+        })
+        .collect();
+
+    match (overflow, props.overflow_mode) {
+        (Some(Overflow::More(remaining)), ChipOverflowMode::Popover) => items.push(html!(
+            <li class="pf-c-chip-group__list-item" key="popover">
+                { render_popover(props, count, remaining) }
+            </li>
+        )),
+        (Some(overflow), _) => items.push(html!(
+            <li class="pf-c-chip-group__list-item" key="overflow">
+                <Chip
+                    overflow=true
+                    text={match overflow {
+                        Overflow::More(remaining) => format!("{remaining} more"),
+                        Overflow::Less => "Show less".to_string(),
+                    }}
+                    onclick={ontoggle}
+                />
+            </li>
+        )),
+        (None, _) => {}
+    }
+
+    items.into_iter().collect()
+}
+
+/// The callback for clicking the chip at the index, if requested.
+fn chip_click(props: &ChipGroupProperties, index: usize) -> Option<Callback<()>> {
+    props
+        .onchipclick
+        .as_ref()
+        .map(|onchipclick| onchipclick.reform(move |()| index))
+}
+
+/// The chips of the group, with the click and close callbacks controlled by the group.
+fn chips(props: &ChipGroupProperties) -> impl Iterator<Item = VChild<Chip>> + '_ {
+    props.children.iter().enumerate().map(|(index, mut chip)| {
+        if let Some(onclick) = chip_click(props, index) {
+            let chip = Rc::make_mut(&mut chip.props);
+            chip.onclick = Some(onclick);
+            // the close button can't be nested in the button of the chip
+            chip.onclose = None;
+        } else if props.read_only {
+            Rc::make_mut(&mut chip.props).onclose = None;
+        } else if let Some(onchipclose) = &props.onchipclose {
+            Rc::make_mut(&mut chip.props).onclose = Some(onchipclose.reform(move |()| index));
+        }
+        chip
+    })
+}
+
+/// The dropdown of the overflow chip, showing the hidden chips.
+fn render_popover(props: &ChipGroupProperties, count: usize, remaining: usize) -> VChild<Dropdown> {
+    html_nested!(
+        <Dropdown
+            plain=true
+            toggle={html!(
+                <span class="pf-c-chip pf-m-overflow">
+                    <span class="pf-c-chip__text">{ format!("{remaining} more") }</span>
+                </span>
+            )}
+            children={ChildrenRenderer::new(hidden_chips(props, count))}
+        />
+    )
+}
+
+/// The chips hidden by a collapsed group.
+fn hidden(props: &ChipGroupProperties, count: usize) -> impl Iterator<Item = VChild<Chip>> + '_ {
+    chips(props).skip(count)
+}
+
+/// The chips hidden by a collapsed group, as dropdown items.
+fn hidden_chips(props: &ChipGroupProperties, count: usize) -> Vec<DropdownChildVariant> {
+    hidden(props, count)
+        .map(|chip| html_nested!(<DropdownItemText>{ chip }</DropdownItemText>).into())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// The keys of the rendered items.
+    fn keys(props: &ChipGroupProperties, expanded: bool) -> Vec<Option<String>> {
+        match render_items(props, expanded, Callback::noop()) {
+            Html::VList(list) => list
+                .iter()
+                .map(|item| item.key().map(ToString::to_string))
+                .collect(),
+            _ => panic!("expected a list"),
+        }
+    }
+
+    fn props(len: usize, collapsible: bool) -> ChipGroupProperties {
+        let children = (0..len).map(|i| html_nested!(<Chip text={i.to_string()} />));
+        yew::props!(ChipGroupProperties {
+            children: ChildrenWithProps::new(children.collect()),
+            collapsible,
+        })
+    }
+
+    #[test]
+    fn test_collapsed() {
+        assert_eq!(overflow(&props(5, false), false), (5, None));
+        assert_eq!(overflow(&props(3, true), false), (3, None));
+        assert_eq!(
+            overflow(&props(5, true), false),
+            (3, Some(Overflow::More(2)))
+        );
+    }
+
+    #[test]
+    fn test_close() {
+        let is_empty = |props: &ChipGroupProperties| match render_close(props, "group") {
+            Html::VList(list) => list.is_empty(),
+            _ => false,
+        };
+
+        let mut props = props(1, false);
+        assert!(is_empty(&props));
+
+        props.onclose = Some(Callback::noop());
+        // not a category
+        assert!(is_empty(&props));
+
+        props.label = Some("Category".into());
+        match render_close(&props, "group") {
+            Html::VTag(tag) => assert_eq!(
+                tag.attributes.iter().find(|(name, _)| *name == "class"),
+                Some(("class", "pf-c-chip-group__close"))
+            ),
+            _ => panic!("expected the close button"),
+        }
+    }
+
+    #[test]
+    fn test_labelledby() {
+        let attributes = |props: &ChipGroupProperties| match render_list(
+            props,
+            "group",
+            false,
+            Callback::noop(),
+        ) {
+            Html::VTag(tag) => tag
+                .attributes
+                .iter()
+                .filter(|(name, _)| name.starts_with("aria-"))
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect::<Vec<_>>(),
+            _ => panic!("expected the list"),
+        };
+
+        let mut props = props(1, false);
+        assert_eq!(
+            attributes(&props),
+            vec![("aria-label".to_string(), "Chip group list".to_string())]
+        );
+
+        props.label = Some("Category".into());
+        assert_eq!(
+            attributes(&props),
+            vec![("aria-labelledby".to_string(), "group-label".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_chip_click() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let mut props = props(3, false);
+        assert!(chip_click(&props, 1).is_none());
+
+        let clicked = Rc::new(Cell::new(None));
+        props.onchipclick = Some({
+            let clicked = clicked.clone();
+            Callback::from(move |index| clicked.set(Some(index)))
+        });
+        chip_click(&props, 1).unwrap().emit(());
+        assert_eq!(clicked.get(), Some(1));
+
+        // the chips become clickable, reporting their index
+        let chip = chips(&props).nth(2).unwrap();
+        chip.props.onclick.as_ref().unwrap().emit(());
+        assert_eq!(clicked.get(), Some(2));
+
+        // items are keyed by their index
+        let items = match render_items(&props, false, Callback::noop()) {
+            Html::VList(list) => list,
+            _ => panic!("expected a list"),
+        };
+        let keys: Vec<_> = items
+            .iter()
+            .take(3)
+            .map(|item| match item {
+                Html::VTag(tag) => tag.key.clone(),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(keys, vec![Some(0.into()), Some(1.into()), Some(2.into())]);
+    }
+
+    #[test]
+    fn test_chip_close() {
+        use std::cell::Cell;
+
+        let children =
+            (0..3).map(|i| html_nested!(<Chip text={i.to_string()} onclose={Callback::noop()} />));
+        let mut props = yew::props!(ChipGroupProperties {
+            children: ChildrenWithProps::new(children.collect()),
+        });
+        assert!(chips(&props).all(|chip| chip.props.onclose.is_some()));
+
+        let closed = Rc::new(Cell::new(None));
+        props.onchipclose = Some({
+            let closed = closed.clone();
+            Callback::from(move |index| closed.set(Some(index)))
+        });
+        let chip = chips(&props).nth(2).unwrap();
+        chip.props.onclose.as_ref().unwrap().emit(());
+        assert_eq!(closed.get(), Some(2));
+
+        // read only removes the close buttons of all chips
+        props.read_only = true;
+        assert!(chips(&props).all(|chip| chip.props.onclose.is_none()));
+    }
+
+    #[test]
+    fn test_chip_click_close() {
+        let children =
+            (0..3).map(|i| html_nested!(<Chip text={i.to_string()} onclose={Callback::noop()} />));
+        let props = yew::props!(ChipGroupProperties {
+            children: ChildrenWithProps::new(children.collect()),
+            onchipclick: Callback::noop(),
+            onchipclose: Callback::noop(),
+        });
+
+        // clickable chips are not closable, also dropping the close callbacks of the chips
+        assert!(
+            chips(&props).all(|chip| chip.props.onclick.is_some() && chip.props.onclose.is_none())
+        );
+    }
+
+    #[test]
+    fn test_popover() {
+        let mut props = props(5, true);
+        props.overflow_mode = ChipOverflowMode::Popover;
+
+        // toggling doesn't expand the group
+        assert_eq!(overflow(&props, true), (3, Some(Overflow::More(2))));
+
+        // the last chips are hidden
+        let texts: Vec<_> = hidden(&props, 3)
+            .map(|chip| chip.props.text.clone())
+            .collect();
+        assert_eq!(texts, vec!["3", "4"]);
+
+        // and shown by the dropdown
+        let dropdown = render_popover(&props, 3, 2);
+        assert!(dropdown.props.plain);
+        assert_eq!(dropdown.props.children.len(), 2);
+
+        let items = match render_items(&props, false, Callback::noop()) {
+            Html::VList(list) => list,
+            _ => panic!("expected a list"),
+        };
+        assert_eq!(items.len(), 4);
+        assert_eq!(keys(&props, false)[3].as_deref(), Some("popover"));
+    }
+
+    #[test]
+    fn test_toggle() {
+        let props = props(5, true);
+        assert_eq!(overflow(&props, true), (5, Some(Overflow::Less)));
+
+        let items = |expanded| match render_items(&props, expanded, Callback::noop()) {
+            Html::VList(list) => list.len(),
+            _ => panic!("expected a list"),
+        };
+        // the chips, plus the overflow chip
+        assert_eq!(items(false), 4);
+        assert_eq!(items(true), 6);
+
+        // all items are keyed, with a stable key for the overflow chip
+        let key = |key: &str| Some(key.to_string());
+        assert_eq!(
+            keys(&props, false),
+            vec![key("0"), key("1"), key("2"), key("overflow")]
+        );
+        assert_eq!(keys(&props, true)[5], key("overflow"));
+        assert!(keys(&props, true).iter().all(Option::is_some));
+    }
+}
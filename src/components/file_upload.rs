@@ -1,3 +1,4 @@
+use crate::{Spinner, SpinnerSize};
 use yew::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, Properties)]
@@ -73,15 +74,7 @@ pub fn file_upload_select(props: &FileUploadDetailsProperties) -> Html {
             { for props.children.iter() }
             if props.processing {
                 <div class="pf-c-file-upload__file-details-spinner">
-                    <span
-                        class="pf-c-spinner pf-m-lg"
-                        role="progressbar"
-                        aria-label="Loading..."
-                    >
-                        <span class="pf-c-spinner__clipper"></span>
-                        <span class="pf-c-spinner__lead-ball"></span>
-                        <span class="pf-c-spinner__tail-ball"></span>
-                    </span>
+                    <Spinner size={SpinnerSize::Lg} />
                 </div>
             }
         </div>
@@ -0,0 +1,81 @@
+//! Masthead
+
+use yew::prelude::*;
+
+/// Properties for [`Masthead`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct MastheadProperties {
+    /// The brand, e.g. a [`Brand`](crate::prelude::Brand) component.
+    #[prop_or_default]
+    pub brand: Children,
+    /// The target of the brand link.
+    #[prop_or("#".into())]
+    pub brand_href: AttrValue,
+    /// The toggle of the navigation, typically a plain button.
+    #[prop_or_default]
+    pub toggle: Children,
+    /// The main content, e.g. a horizontal navigation.
+    #[prop_or_default]
+    pub content: Children,
+    /// Tools, shown at the end of the content.
+    #[prop_or_default]
+    pub tools: Children,
+
+    #[prop_or_default]
+    pub id: AttrValue,
+}
+
+/// Masthead component
+///
+/// > A **masthead** contains global properties such as logotype, navigation and settings in an organized fashion, and it is accessible from all parts of the application.
+///
+/// See: <https://www.patternfly.org/v4/components/masthead>
+///
+/// ## Properties
+///
+/// Defined by [`MastheadProperties`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///     let brand = html!(<Brand src="logo.svg" alt="Logo" />);
+///     let tools = html!(<Button variant={ButtonVariant::Plain} icon={Icon::QuestionCircle} />);
+///
+///     html!(
+///         <Masthead
+///             brand={Children::new(vec![brand])}
+///             tools={Children::new(vec![tools])}
+///         />
+///     )
+/// }
+/// ```
+#[function_component(Masthead)]
+pub fn masthead(props: &MastheadProperties) -> Html {
+    html!(
+        <header class="pf-c-masthead" id={&props.id}>
+            if !props.toggle.is_empty() {
+                <span class="pf-c-masthead__toggle">
+                    { for props.toggle.iter() }
+                </span>
+            }
+            <div class="pf-c-masthead__main">
+                <a class="pf-c-masthead__brand" href={&props.brand_href}>
+                    { for props.brand.iter() }
+                </a>
+            </div>
+            <div class="pf-c-masthead__content">
+                { for props.content.iter() }
+                if !props.tools.is_empty() {
+                    <div class="pf-u-ml-auto">
+                        { for props.tools.iter() }
+                    </div>
+                }
+            </div>
+        </header>
+    )
+}
@@ -30,6 +30,7 @@ pub mod input_group;
 pub mod label;
 pub mod list;
 pub mod login_page;
+pub mod masthead;
 pub mod modal;
 pub mod nav;
 pub mod page;
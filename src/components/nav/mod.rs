@@ -6,7 +6,7 @@ mod router;
 pub use router::*;
 use std::collections::HashSet;
 
-use crate::{Icon, Id};
+use crate::{utils::use_random_id, Icon, Id};
 use std::fmt::Debug;
 use yew::prelude::*;
 
@@ -83,10 +83,34 @@ pub struct NavItemProperties {
     pub target: String,
     #[prop_or_default]
     pub external: bool,
+    /// Mark the item as the current page.
+    ///
+    /// A [`NavExpandable`] containing a selected item gets expanded.
+    #[prop_or_default]
+    pub selected: bool,
 }
 
 #[function_component(NavItem)]
 pub fn nav_item(props: &NavItemProperties) -> Html {
+    let id = use_random_id();
+    let expandable = use_expandable();
+    use_effect_with_deps(
+        move |selected| {
+            if let Some(expandable) = expandable {
+                expandable.state(*id, *selected)
+            }
+        },
+        props.selected,
+    );
+
+    html! (
+        <li class="pf-c-nav__item">
+            { render_link(props) }
+        </li>
+    )
+}
+
+fn render_link(props: &NavItemProperties) -> Html {
     let mut target = props.target.to_string();
     if target.is_empty() && props.external {
         target = "_blank".to_string();
@@ -98,19 +122,23 @@ pub fn nav_item(props: &NavItemProperties) -> Html {
         props.to.clone()
     };
 
+    let mut class = classes!("pf-c-nav__link");
+    if props.selected {
+        class.push("pf-m-current");
+    }
+
     html! (
-        <li class="pf-c-nav__item">
-            <a
-                href={href}
-                class="pf-c-nav__link"
-                target={target}
-            >
-                { for props.children.iter() }
-                if props.external {
-                    <span class="pf-u-ml-sm pf-u-font-size-sm">{Icon::ExternalLinkAlt}</span>
-                }
-            </a>
-        </li>
+        <a
+            href={href}
+            {class}
+            target={target}
+            aria-current={props.selected.then_some("page")}
+        >
+            { for props.children.iter() }
+            if props.external {
+                <span class="pf-u-ml-sm pf-u-font-size-sm">{Icon::ExternalLinkAlt}</span>
+            }
+        </a>
     )
 }
 
@@ -249,18 +277,70 @@ impl Component for NavExpandable {
 
 impl NavExpandable {
     fn is_expanded(&self, ctx: &Context<Self>) -> bool {
-        // if we have a current state, that will always override.
-        self.expanded.unwrap_or_else(|| {
-            // if any child is currently active.
-            let active = !self.active.is_empty();
-
-            ctx.props().expanded || active
-        })
+        is_expanded(self.expanded, ctx.props().expanded, !self.active.is_empty())
     }
 }
 
+/// Evaluate the expanded state of a [`NavExpandable`].
+fn is_expanded(current: Option<bool>, expanded: bool, active: bool) -> bool {
+    // if we have a current state, that will always override.
+    current.unwrap_or(
+        // if any child is currently active.
+        expanded || active,
+    )
+}
+
 /// Access a wrapping [`Expandable`] content.
 #[hook]
 pub fn use_expandable() -> Option<Expandable> {
     use_context::<Expandable>()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn link(selected: bool) -> Vec<(String, String)> {
+        match render_link(&yew::props!(NavItemProperties {
+            to: "/home".to_string(),
+            selected,
+        })) {
+            Html::VTag(tag) => tag
+                .attributes
+                .iter()
+                .filter(|(name, _)| *name == "class" || *name == "aria-current")
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            other => panic!("expected the link: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_selected() {
+        assert_eq!(
+            link(false),
+            vec![("class".to_string(), "pf-c-nav__link".to_string())]
+        );
+        assert_eq!(
+            link(true),
+            vec![
+                ("aria-current".to_string(), "page".to_string()),
+                (
+                    "class".to_string(),
+                    "pf-c-nav__link pf-m-current".to_string()
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expanded() {
+        assert!(!is_expanded(None, false, false));
+        assert!(is_expanded(None, true, false));
+        // expanded by a selected child
+        assert!(is_expanded(None, false, true));
+        // until the user collapses it
+        assert!(!is_expanded(Some(false), false, true));
+        assert!(is_expanded(Some(true), false, false));
+    }
+}
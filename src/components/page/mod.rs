@@ -1,12 +1,28 @@
 //! Full Page components
+use crate::{use_prop_id, Breakpoint, Icon, Masthead};
+use gloo_events::EventListener;
 use std::rc::Rc;
 use yew::prelude::*;
 
+mod notification;
 mod section;
 mod sidebar;
+mod tools;
 
+pub use notification::*;
 pub use section::*;
 pub use sidebar::*;
+pub use tools::*;
+
+/// The layout of the navigation of a [`Page`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum PageNavVariant {
+    /// Navigation using the sidebar, toggled by the masthead.
+    #[default]
+    Vertical,
+    /// Navigation using the `nav` section of the masthead, without a sidebar.
+    Horizontal,
+}
 
 /// Properties for [`Page`]
 #[derive(Clone, PartialEq, Properties)]
@@ -21,13 +37,63 @@ pub struct PageProperties {
     pub logo: Children,
     #[prop_or_default]
     pub nav: Children,
+    /// The layout of the navigation.
+    ///
+    /// Using [`PageNavVariant::Horizontal`], neither the sidebar, nor its toggle, are rendered.
+    #[prop_or_default]
+    pub nav_variant: PageNavVariant,
+    /// The icon of the sidebar toggle, defaults to [`Icon::Bars`].
+    #[prop_or_default]
+    pub toggle_icon: Option<Icon>,
+    /// A custom masthead, replacing the one built from the logo, navigation, and tools.
+    ///
+    /// The masthead is then also responsible for toggling the sidebar, controlling the state
+    /// using [`Self::open`] and [`Self::ontoggle`].
+    #[prop_or_default]
+    pub masthead: Option<Html>,
+    /// Breadcrumbs, shown above the page content.
+    ///
+    /// This normally contains a single [`Breadcrumb`](crate::prelude::Breadcrumb) component.
+    #[prop_or_default]
+    pub breadcrumb: Children,
+    /// The open state of the sidebar.
+    ///
+    /// This is only the initial state, unless [`Self::ontoggle`] is set. In which case the state
+    /// is controlled by the parent.
     #[prop_or(true)]
     pub open: bool,
+    /// Called when the user toggles the sidebar, with the requested open state.
+    ///
+    /// Setting this callback makes the parent responsible for the state, using [`Self::open`].
+    #[prop_or_default]
+    pub ontoggle: Option<Callback<bool>>,
+    /// Automatically collapse the sidebar when the viewport gets smaller than the breakpoint,
+    /// and open it again when it gets larger.
+    ///
+    /// This has no effect when the state is controlled by the parent, using [`Self::ontoggle`].
+    #[prop_or_default]
+    pub managed_sidebar_breakpoint: Option<Breakpoint>,
     #[prop_or_default]
     pub full_height: bool,
 
     #[prop_or_default]
     pub id: AttrValue,
+
+    /// The id of the main section, random if not set.
+    ///
+    /// This allows addressing the main section, e.g. using anchor links.
+    #[prop_or_default]
+    pub main_id: Option<String>,
+    /// Scroll the main section on its own, keeping the masthead and the sidebar in place.
+    ///
+    /// This requires the page to have a limited height, e.g. using [`Self::full_height`].
+    #[prop_or_default]
+    pub main_scrollable: bool,
+    /// The text of a "skip to content" link, which allows skipping to the main section.
+    ///
+    /// The link is only rendered when the text is set.
+    #[prop_or_default]
+    pub skip_to_content: Option<AttrValue>,
 }
 
 /// A full page
@@ -43,20 +109,46 @@ pub struct PageProperties {
 /// ## Elements
 ///
 /// * **Sidebar**: Contains a single [`PageSidebar`], hosting the main navigation.
+/// * **Masthead**: A custom [`Masthead`], replacing the default one built from the following
+///   elements.
 /// * **Navigation**: The top header navigation section.
-/// * **Tools**: Tools, shown in the header section of the page.
+/// * **Tools**: Tools, shown in the header section of the page, probably grouped using
+///   [`PageHeaderToolsGroup`] components.
 /// * **Logo**: A logo, show in the navigation header section.
+/// * **Breadcrumb**: Breadcrumbs, shown at the top of the main section.
 /// * **Children**: The actual page content, probably wrapped into [`PageSection`] components.
 ///
 #[function_component(Page)]
 pub fn page(props: &PageProperties) -> Html {
-    let open = use_state_eq(|| true);
+    let state = use_state_eq(|| initial_state(props));
+    let main_id = use_prop_id(props.main_id.clone());
 
+    {
+        let state = state.clone();
+        use_effect_with_deps(
+            move |breakpoint| {
+                let listener = breakpoint.map(|breakpoint| {
+                    let mut managed = ManagedSidebar::new(breakpoint);
+                    let mut resize = move || {
+                        if let Some(open) = viewport().and_then(|(w, h)| managed.resize(w, h)) {
+                            state.set(open);
+                        }
+                    };
+                    resize();
+                    EventListener::new(&gloo_utils::window(), "resize", move |_| resize())
+                });
+                move || drop(listener)
+            },
+            props
+                .managed_sidebar_breakpoint
+                .filter(|_| props.ontoggle.is_none()),
+        );
+    }
+
+    let open = sidebar_open(props, *state);
     let onclick = {
-        let open = open.clone();
-        Callback::from(move |_| {
-            open.set(!(*open));
-        })
+        let state = state.clone();
+        toggle(props, open, Callback::from(move |open| state.set(open))).reform(|_| ())
     };
 
     let mut class = classes!("pf-c-page");
@@ -67,40 +159,336 @@ pub fn page(props: &PageProperties) -> Html {
 
     html! (
         <div {class} id={&props.id}>
-            <header class="pf-c-page__header">
-                <div class="pf-c-page__header-brand">
-
-                    if !props.sidebar.is_empty() {
-                        <div class="pf-c-page__header-brand-toggle">
-                            <button
-                                aria-expanded={(*open).to_string()}
-                                class="pf-c-button pf-m-plain"
-                                type="button"
-                                {onclick}
-                                >
-                                <i class="fas fa-bars" aria-hidden="true"/>
-                            </button>
-                        </div>
-                    }
-
-                    <a href="#" class="pf-c-page__header-brand-link"> {
-                        for props.logo.iter()
-                    } </a>
-
-                </div>
-                <div class="pf-c-page__header-nav">{for props.nav.iter()}</div>
-                <div class="pf-c-page__header-tools"> { for props.tools.iter() }</div>
-            </header>
-
-            { for props.sidebar.iter().map(|mut s|{
-                let props = Rc::make_mut(&mut s.props);
-                props.open = *open;
-                s
-            }) }
-
-            <main class="pf-c-page__main" tabindex="-1">
-                { for props.children.iter() }
-            </main>
+            { render_skip_to_content(props, &main_id) }
+            { render_masthead(props, open, onclick) }
+
+            if props.nav_variant == PageNavVariant::Vertical {
+                { for props.sidebar.iter().map(|mut s|{
+                    let props = Rc::make_mut(&mut s.props);
+                    props.open = open;
+                    s
+                }) }
+            }
+
+            { render_main(props, &main_id) }
         </div>
     )
 }
+
+fn render_main(props: &PageProperties, main_id: &str) -> Html {
+    let style = props.main_scrollable.then_some("overflow-y: auto;");
+
+    html!(
+        <main class="pf-c-page__main" id={main_id.to_string()} tabindex="-1" {style}>
+            { render_breadcrumb(props) }
+            { for props.children.iter() }
+        </main>
+    )
+}
+
+fn render_masthead(props: &PageProperties, open: bool, onclick: Callback<MouseEvent>) -> Html {
+    if let Some(masthead) = &props.masthead {
+        return masthead.clone();
+    }
+
+    let toggle = Children::new(render_toggle(props, open, onclick).into_iter().collect());
+
+    html!(
+        <Masthead
+            {toggle}
+            brand={props.logo.clone()}
+            content={props.nav.clone()}
+            tools={props.tools.clone()}
+        />
+    )
+}
+
+/// Render the toggle of the sidebar, if there is one.
+fn render_toggle(
+    props: &PageProperties,
+    open: bool,
+    onclick: Callback<MouseEvent>,
+) -> Option<Html> {
+    if props.sidebar.is_empty() || props.nav_variant == PageNavVariant::Horizontal {
+        return None;
+    }
+
+    Some(html!(
+        <button
+            aria-expanded={open.to_string()}
+            aria-label="Global navigation"
+            class="pf-c-button pf-m-plain"
+            type="button"
+            {onclick}
+        >
+            { props.toggle_icon.unwrap_or(Icon::Bars) }
+        </button>
+    ))
+}
+
+fn render_skip_to_content(props: &PageProperties, main_id: &str) -> Html {
+    html!(
+        if let Some(text) = &props.skip_to_content {
+            <a class="pf-c-skip-to-content pf-c-button pf-m-primary" href={format!("#{main_id}")}>
+                { text }
+            </a>
+        }
+    )
+}
+
+fn render_breadcrumb(props: &PageProperties) -> Html {
+    match props.breadcrumb.is_empty() {
+        true => html!(),
+        false => html!(
+            <section class="pf-c-page__main-breadcrumb">
+                { for props.breadcrumb.iter() }
+            </section>
+        ),
+    }
+}
+
+/// Get the size of the viewport.
+fn viewport() -> Option<(f64, f64)> {
+    let window = gloo_utils::window();
+    let width = window.inner_width().ok()?.as_f64()?;
+    let height = window.inner_height().ok()?.as_f64()?;
+    Some((width, height))
+}
+
+/// Tracks the size of the viewport, relative to a breakpoint.
+struct ManagedSidebar {
+    breakpoint: Breakpoint,
+    above: bool,
+}
+
+impl ManagedSidebar {
+    /// Start tracking, assuming the viewport is at least the size of the breakpoint, keeping the
+    /// initial state of the sidebar in that case.
+    fn new(breakpoint: Breakpoint) -> Self {
+        Self {
+            breakpoint,
+            above: true,
+        }
+    }
+
+    /// Report a new size of the viewport, returning the new open state when crossing the
+    /// breakpoint.
+    fn resize(&mut self, width: f64, height: f64) -> Option<bool> {
+        let above = self.breakpoint.matches(width, height);
+        (std::mem::replace(&mut self.above, above) != above).then_some(above)
+    }
+}
+
+/// The initial state of the sidebar managed by the page, seeded from [`PageProperties::open`].
+fn initial_state(props: &PageProperties) -> bool {
+    props.open
+}
+
+/// Evaluate the open state of the sidebar, `state` being the state managed by the page.
+fn sidebar_open(props: &PageProperties, state: bool) -> bool {
+    match props.ontoggle {
+        Some(_) => props.open,
+        None => state,
+    }
+}
+
+/// Create the callback toggling the sidebar, `state` updating the state managed by the page.
+fn toggle(props: &PageProperties, open: bool, state: Callback<bool>) -> Callback<()> {
+    let next = !open;
+    match props.ontoggle.clone() {
+        Some(ontoggle) => Callback::from(move |()| ontoggle.emit(next)),
+        None => state.reform(move |()| next),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    fn recorder() -> (Rc<Cell<Option<bool>>>, Callback<bool>) {
+        let value = Rc::new(Cell::new(None));
+        let callback = {
+            let value = value.clone();
+            Callback::from(move |v| value.set(Some(v)))
+        };
+        (value, callback)
+    }
+
+    #[test]
+    fn test_masthead() {
+        let props = yew::props!(PageProperties {});
+        assert!(matches!(
+            render_masthead(&props, true, Callback::noop()),
+            Html::VComp(_)
+        ));
+
+        let props = yew::props!(PageProperties {
+            masthead: html!(<header>{ "Custom" }</header>),
+        });
+        assert_eq!(
+            render_masthead(&props, true, Callback::noop()),
+            html!(<header>{ "Custom" }</header>)
+        );
+    }
+
+    #[test]
+    fn test_nav_variant() {
+        let sidebar = || ChildrenWithProps::new(vec![html_nested!(<PageSidebar />)]);
+
+        let props = yew::props!(PageProperties {});
+        assert!(render_toggle(&props, true, Callback::noop()).is_none());
+
+        let props = yew::props!(PageProperties { sidebar: sidebar() });
+        assert!(render_toggle(&props, true, Callback::noop()).is_some());
+
+        let props = yew::props!(PageProperties {
+            sidebar: sidebar(),
+            nav_variant: PageNavVariant::Horizontal,
+        });
+        assert!(render_toggle(&props, true, Callback::noop()).is_none());
+    }
+
+    #[test]
+    fn test_toggle_icon() {
+        let sidebar = || ChildrenWithProps::new(vec![html_nested!(<PageSidebar />)]);
+        let icon = |props: &PageProperties| match render_toggle(props, true, Callback::noop()) {
+            Some(Html::VTag(tag)) => tag.children().first().cloned(),
+            other => panic!("expected the toggle: {other:?}"),
+        };
+
+        let props = yew::props!(PageProperties { sidebar: sidebar() });
+        assert_eq!(icon(&props), Some(Icon::Bars.as_html()));
+
+        let props = yew::props!(PageProperties {
+            sidebar: sidebar(),
+            toggle_icon: Icon::Th,
+        });
+        assert_eq!(icon(&props), Some(Icon::Th.as_html()));
+    }
+
+    #[test]
+    fn test_skip_to_content() {
+        let props = yew::props!(PageProperties {});
+        assert_eq!(render_skip_to_content(&props, "main"), html!());
+
+        let props = yew::props!(PageProperties {
+            skip_to_content: "Skip to content",
+        });
+        let link = match render_skip_to_content(&props, "main") {
+            Html::VList(mut list) => list.pop(),
+            _ => None,
+        };
+        match link {
+            Some(Html::VTag(tag)) => {
+                assert_eq!(
+                    tag.attributes.iter().find(|(name, _)| *name == "href"),
+                    Some(("href", "#main"))
+                )
+            }
+            _ => panic!("expected the link"),
+        }
+    }
+
+    #[test]
+    fn test_main() {
+        let attr = |html: &Html, name: &str| match html {
+            Html::VTag(tag) => tag
+                .attributes
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string()),
+            _ => panic!("expected the main section"),
+        };
+
+        let props = yew::props!(PageProperties {});
+        let main = render_main(&props, "main-1");
+        assert_eq!(attr(&main, "id").as_deref(), Some("main-1"));
+        assert_eq!(attr(&main, "style"), None);
+
+        let props = yew::props!(PageProperties {
+            main_scrollable: true
+        });
+        let main = render_main(&props, "main-1");
+        assert_eq!(attr(&main, "style").as_deref(), Some("overflow-y: auto;"));
+    }
+
+    #[test]
+    fn test_breadcrumb() {
+        let props = yew::props!(PageProperties {});
+        assert_eq!(render_breadcrumb(&props), html!());
+
+        let props = yew::props!(PageProperties {
+            breadcrumb: Children::new(vec![html!("Home")]),
+        });
+        match render_breadcrumb(&props) {
+            Html::VTag(tag) => assert_eq!(tag.tag(), "section"),
+            _ => panic!("expected the breadcrumb section"),
+        }
+    }
+
+    #[test]
+    fn test_managed_sidebar() {
+        let mut managed = ManagedSidebar::new(Breakpoint::XLarge);
+
+        // keep the initial state
+        assert_eq!(managed.resize(1280.0, 800.0), None);
+        assert_eq!(managed.resize(1024.0, 800.0), Some(false));
+        // don't override the user, unless crossing the breakpoint again
+        assert_eq!(managed.resize(800.0, 800.0), None);
+        assert_eq!(managed.resize(1200.0, 800.0), Some(true));
+
+        let mut managed = ManagedSidebar::new(Breakpoint::Large);
+        assert_eq!(managed.resize(375.0, 800.0), Some(false));
+    }
+
+    #[test]
+    fn test_uncontrolled() {
+        let props = yew::props!(PageProperties { open: false });
+        let (state, set_state) = recorder();
+
+        // the prop is only the initial state
+        assert!(sidebar_open(&props, true));
+
+        toggle(&props, true, set_state).emit(());
+        assert_eq!(state.get(), Some(false));
+    }
+
+    #[test]
+    fn test_initially_closed() {
+        let props = yew::props!(PageProperties {
+            sidebar: ChildrenWithProps::new(vec![html_nested!(<PageSidebar />)]),
+            open: false,
+        });
+
+        // the state of the page, as seeded when mounting it
+        let open = sidebar_open(&props, initial_state(&props));
+        assert!(!open);
+
+        match render_toggle(&props, open, Callback::noop()) {
+            Some(Html::VTag(tag)) => assert_eq!(
+                tag.attributes
+                    .iter()
+                    .find_map(|(key, value)| (key == "aria-expanded").then_some(value)),
+                Some("false")
+            ),
+            other => panic!("expected the toggle: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_controlled() {
+        let (requested, ontoggle) = recorder();
+        let props = yew::props!(PageProperties {
+            open: false,
+            ontoggle,
+        });
+        let (state, set_state) = recorder();
+
+        assert!(!sidebar_open(&props, true));
+
+        toggle(&props, false, set_state).emit(());
+        assert_eq!(requested.get(), Some(true));
+        assert_eq!(state.get(), None);
+    }
+}
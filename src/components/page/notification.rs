@@ -0,0 +1,164 @@
+use crate::Icon;
+use yew::prelude::*;
+
+/// The state of the notifications, shown by a [`PageHeaderNotificationBadge`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NotificationBadgeVariant {
+    /// All notifications have been read.
+    #[default]
+    Read,
+    /// There are unread notifications.
+    Unread,
+    /// There are unread notifications which need attention, e.g. errors.
+    Attention,
+}
+
+impl NotificationBadgeVariant {
+    fn as_class(&self) -> &'static str {
+        match self {
+            Self::Read => "pf-m-read",
+            Self::Unread => "pf-m-unread",
+            Self::Attention => "pf-m-attention",
+        }
+    }
+}
+
+/// Properties for [`PageHeaderNotificationBadge`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct PageHeaderNotificationBadgeProperties {
+    /// The number of notifications, not shown if zero.
+    #[prop_or_default]
+    pub count: usize,
+    #[prop_or_default]
+    pub variant: NotificationBadgeVariant,
+    /// Called when the user clicks the badge, e.g. for toggling a notification drawer.
+    #[prop_or_default]
+    pub onclick: Callback<()>,
+    /// Show the badge as expanded, while the notifications it toggles are shown.
+    #[prop_or_default]
+    pub expanded: bool,
+    #[prop_or("Notifications".into())]
+    pub aria_label: AttrValue,
+}
+
+/// A notification badge, for the header tools of a [`Page`](crate::prelude::Page).
+///
+/// The badge shows the state of the notifications, and is typically used to toggle a notification
+/// drawer. It can still be used on its own.
+///
+/// ## Properties
+///
+/// Defined by [`PageHeaderNotificationBadgeProperties`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///     html!(
+///         <PageHeaderNotificationBadge
+///             count={3}
+///             variant={NotificationBadgeVariant::Unread}
+///             onclick={Callback::from(|_| log::info!("Toggle the drawer"))}
+///         />
+///     )
+/// }
+/// ```
+#[function_component(PageHeaderNotificationBadge)]
+pub fn page_header_notification_badge(props: &PageHeaderNotificationBadgeProperties) -> Html {
+    let onclick = props.onclick.reform(|_| ());
+
+    html!(
+        <button
+            class="pf-c-button pf-m-plain"
+            type="button"
+            aria-label={&props.aria_label}
+            aria-expanded={props.expanded.to_string()}
+            {onclick}
+        >
+            { render_badge(props) }
+        </button>
+    )
+}
+
+fn render_badge(props: &PageHeaderNotificationBadgeProperties) -> Html {
+    let icon = match props.variant {
+        NotificationBadgeVariant::Attention => Icon::AttentionBell,
+        _ => Icon::Bell,
+    };
+
+    html!(
+        <span class={classes!("pf-c-notification-badge", props.variant.as_class())}>
+            { icon }
+            if props.count > 0 {
+                <span class="pf-c-notification-badge__count">{ props.count }</span>
+            }
+        </span>
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use yew::virtual_dom::VTag;
+
+    fn badge(count: usize, variant: NotificationBadgeVariant) -> Box<VTag> {
+        match render_badge(&yew::props!(PageHeaderNotificationBadgeProperties {
+            count,
+            variant
+        })) {
+            Html::VTag(tag) => tag,
+            _ => panic!("expected the badge"),
+        }
+    }
+
+    fn class(tag: &VTag) -> Option<&str> {
+        tag.attributes
+            .iter()
+            .find_map(|(name, value)| (name == "class").then_some(value))
+    }
+
+    /// The count element, if any.
+    fn count(tag: &VTag) -> Option<Html> {
+        match tag.children().get(1) {
+            Some(Html::VList(list)) => list.first().cloned(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_variant() {
+        for (variant, expected) in [
+            (
+                NotificationBadgeVariant::Read,
+                "pf-c-notification-badge pf-m-read",
+            ),
+            (
+                NotificationBadgeVariant::Unread,
+                "pf-c-notification-badge pf-m-unread",
+            ),
+            (
+                NotificationBadgeVariant::Attention,
+                "pf-c-notification-badge pf-m-attention",
+            ),
+        ] {
+            assert_eq!(class(&badge(0, variant)), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_count() {
+        assert_eq!(count(&badge(0, Default::default())), None);
+
+        match count(&badge(5, Default::default())) {
+            Some(Html::VTag(tag)) => {
+                assert_eq!(class(&tag), Some("pf-c-notification-badge__count"));
+                assert_eq!(tag.children().first(), Some(&html!({ 5 })));
+            }
+            other => panic!("expected the count: {other:?}"),
+        }
+    }
+}
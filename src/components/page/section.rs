@@ -57,14 +57,24 @@ pub struct PageSectionProperties {
     pub variant: PageSectionVariant,
     #[prop_or_default]
     pub fill: PageSectionFill,
+    /// Limit the width of the content, wrapping it in a body element.
     #[prop_or_default]
     pub limit_width: bool,
+    /// Remove the padding of the section.
+    #[prop_or_default]
+    pub no_padding: bool,
     #[prop_or_default]
     pub shadow: PageSectionShadow,
+    /// Center the content, in combination with [`Self::limit_width`].
     #[prop_or_default]
     pub align_center: bool,
+    /// Scroll the content of the section on its own, when it overflows.
     #[prop_or_default]
     pub overflow_scroll: bool,
+    /// Stick the section to the top, or bottom, of the page while scrolling.
+    ///
+    /// This is supported for width and height breakpoints, e.g. only sticking to the top if the
+    /// viewport is large enough: `[PageSectionSticky::Top.lg_height()]`.
     #[prop_or_default]
     pub sticky: WithBreakpoints<PageSectionSticky>,
 
@@ -164,6 +174,31 @@ impl IntoPropValue<PageSectionFill> for bool {
 /// ```
 #[function_component(PageSection)]
 pub fn page_section(props: &PageSectionProperties) -> Html {
+    let class = section_classes(props);
+
+    // render
+
+    html! (
+        <section {class} id={&props.id} hidden={props.hidden}>
+            { render_body(props) }
+        </section>
+    )
+}
+
+fn render_body(props: &PageSectionProperties) -> Html {
+    match props.limit_width {
+        true => html!(
+            <div class="pf-c-page__main-body">
+                { for props.children.iter() }
+            </div>
+        ),
+        false => html!(
+            {for props.children.iter()}
+        ),
+    }
+}
+
+fn section_classes(props: &PageSectionProperties) -> Classes {
     // start with the main type
 
     let mut class = props.r#type.as_classes();
@@ -187,42 +222,66 @@ pub fn page_section(props: &PageSectionProperties) -> Html {
         class.push("pf-m-overflow-scroll");
     }
 
-    // render
+    if props.no_padding {
+        class.push("pf-m-no-padding");
+    }
 
-    html! (
-        <section {class} id={&props.id} hidden={props.hidden}>
-            {
-                match props.limit_width {
-                    true => html!(
-                        <div class="pf-c-page__main-body">
-                            { for props.children.iter() }
-                        </div>
-                    ),
-                    false => html!(
-                        {for props.children.iter()}
-                    ),
-                }
-            }
-        </section>
-    )
+    class
 }
 
 /// Properties for [`PageSectionGroup`]
 #[derive(PartialEq, Properties)]
 pub struct PageSectionGroupProperties {
     pub children: Children,
+    /// The background of the group, shared by its sections.
+    #[prop_or_default]
+    pub variant: PageSectionVariant,
     #[prop_or_default]
     pub shadow: PageSectionShadow,
+    /// Stick the whole group to the top, or bottom, of the page while scrolling.
     #[prop_or_default]
     pub sticky: WithBreakpoints<PageSectionSticky>,
     #[prop_or_default]
     pub overflow_scroll: bool,
 }
 
+/// A group of [`PageSection`]s, sharing their sticky behavior and background.
+///
+/// Groups can be used in the main content of a [`Page`](crate::prelude::Page), just like
+/// sections.
+///
+/// ## Properties
+///
+/// Defined by [`PageSectionGroupProperties`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::*;
+///
+/// #[function_component(MyPage)]
+/// fn my_page() -> Html {
+///   html!(
+///     <Page>
+///       <PageSectionGroup sticky={[PageSectionSticky::Top]} variant={PageSectionVariant::Light}>
+///         <PageSection r#type={PageSectionType::Breadcrumbs}>{"breadcrumbs"}</PageSection>
+///         <PageSection>{"title"}</PageSection>
+///       </PageSectionGroup>
+///       <PageSection>{"my content"}</PageSection>
+///     </Page>
+///   )
+/// }
+/// ```
 #[function_component(PageSectionGroup)]
 pub fn page_section_group(props: &PageSectionGroupProperties) -> Html {
+    render_group(props)
+}
+
+fn render_group(props: &PageSectionGroupProperties) -> Html {
     let mut class = Classes::from("pf-c-page__main-group");
 
+    class.extend_from(&props.variant);
     class.extend_from(&props.shadow);
     class.extend_from(&props.sticky);
 
@@ -236,3 +295,131 @@ pub fn page_section_group(props: &PageSectionGroupProperties) -> Html {
         </div>
     )
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::WithBreakpointExt;
+
+    #[test]
+    fn test_variants() {
+        let class =
+            |variant| section_classes(&yew::props!(PageSectionProperties { variant })).to_string();
+
+        assert_eq!(
+            class(PageSectionVariant::Default),
+            "pf-c-page__main-section"
+        );
+        assert_eq!(
+            class(PageSectionVariant::Light),
+            "pf-c-page__main-section pf-m-light"
+        );
+        assert_eq!(
+            class(PageSectionVariant::Dark),
+            "pf-c-page__main-section pf-m-dark-200"
+        );
+        assert_eq!(
+            class(PageSectionVariant::Darker),
+            "pf-c-page__main-section pf-m-dark-100"
+        );
+    }
+
+    #[test]
+    fn test_sticky() {
+        let class = |sticky: WithBreakpoints<PageSectionSticky>| {
+            section_classes(&yew::props!(PageSectionProperties {
+                variant: PageSectionVariant::Light,
+                sticky,
+            }))
+            .to_string()
+        };
+
+        assert_eq!(
+            class(PageSectionSticky::Top.into()),
+            "pf-c-page__main-section pf-m-light pf-m-sticky-top"
+        );
+        assert_eq!(
+            class(PageSectionSticky::Bottom.into()),
+            "pf-c-page__main-section pf-m-light pf-m-sticky-bottom"
+        );
+        assert_eq!(
+            class([PageSectionSticky::Top.lg_height()].into()),
+            "pf-c-page__main-section pf-m-light pf-m-sticky-top-on-lg-height"
+        );
+    }
+
+    #[test]
+    fn test_padding() {
+        let props = yew::props!(PageSectionProperties {
+            fill: true,
+            no_padding: true,
+        });
+        assert_eq!(
+            section_classes(&props).to_string(),
+            "pf-c-page__main-section pf-m-fill pf-m-no-padding"
+        );
+    }
+
+    #[test]
+    fn test_limit_width() {
+        let props = yew::props!(PageSectionProperties {
+            variant: PageSectionVariant::Light,
+            limit_width: true,
+            align_center: true,
+            overflow_scroll: true,
+            no_padding: true,
+        });
+        assert_eq!(
+            section_classes(&props).to_string(),
+            "pf-c-page__main-section pf-m-light pf-m-limit-width pf-m-align-center pf-m-overflow-scroll pf-m-no-padding"
+        );
+    }
+
+    #[test]
+    fn test_group() {
+        let props = yew::props!(PageSectionGroupProperties {
+            variant: PageSectionVariant::Light,
+            sticky: [PageSectionSticky::Top],
+            children: Children::new(vec![
+                html!(<PageSection>{"first"}</PageSection>),
+                html!(<PageSection>{"second"}</PageSection>),
+            ]),
+        });
+
+        match render_group(&props) {
+            Html::VTag(tag) => {
+                assert_eq!(
+                    tag.attributes.iter().next(),
+                    Some(("class", "pf-c-page__main-group pf-m-light pf-m-sticky-top"))
+                );
+                // the sections are rendered inside the group
+                assert_eq!(tag.children().len(), 2);
+                assert!(matches!(tag.children().first(), Some(Html::VComp(_))));
+            }
+            other => panic!("expected the group: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_body() {
+        let body = |limit_width| {
+            render_body(&yew::props!(PageSectionProperties {
+                limit_width,
+                children: Children::new(vec![html!("content")]),
+            }))
+        };
+
+        match body(true) {
+            Html::VTag(tag) => {
+                assert_eq!(
+                    tag.attributes.iter().next(),
+                    Some(("class", "pf-c-page__main-body"))
+                );
+                assert_eq!(tag.children().len(), 1);
+            }
+            other => panic!("expected the body: {other:?}"),
+        }
+
+        assert!(matches!(body(false), Html::VList(_)));
+    }
+}
@@ -1,5 +1,15 @@
 use yew::prelude::*;
 
+/// The behavior of a closed [`PageSidebar`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum PageSidebarBehavior {
+    /// Slide the sidebar away when it is closed.
+    #[default]
+    Expandable,
+    /// Shrink the sidebar to a narrow rail when it is closed, e.g. only showing icons.
+    Collapsible,
+}
+
 /// Properties for [`PageSidebar`]
 #[derive(Clone, PartialEq, Properties)]
 pub struct PageSidebarProperties {
@@ -7,8 +17,18 @@ pub struct PageSidebarProperties {
     pub children: Children,
     #[prop_or(true)]
     pub open: bool,
+    /// The behavior when the sidebar is closed.
+    ///
+    /// The toggle of the [`Page`](crate::prelude::Page) then switches between the expanded and
+    /// the collapsed sidebar.
+    #[prop_or_default]
+    pub behavior: PageSidebarBehavior,
 }
 
+/// The style of a collapsed sidebar, shrinking it to a narrow rail.
+const RAIL_STYLE: &str =
+    "--pf-c-page__sidebar--Width: 4.5rem; --pf-c-page__sidebar--TranslateX: 0;";
+
 /// The sidebar component of a [`Page`](crate::prelude::Page).
 ///
 /// ## Properties
@@ -16,20 +36,70 @@ pub struct PageSidebarProperties {
 /// Defined by [`PageSidebarProperties`].
 #[function_component(PageSidebar)]
 pub fn page_sidebar(props: &PageSidebarProperties) -> Html {
-    let mut classes = match props.open {
-        true => classes!["pf-m-expanded"],
-        false => classes!["pf-m-collapsed"],
-    };
-
-    classes.push("pf-c-page__sidebar");
+    let (classes, hidden, style) = sidebar_state(props);
 
     html! (
         <div
-            aria-hidden={(!props.open).to_string()}
-            class={classes}>
+            aria-hidden={hidden.to_string()}
+            class={classes}
+            {style}
+        >
             <div class="pf-c-page__sidebar-body">
                 { for props.children.iter() }
             </div>
         </div>
     )
 }
+
+/// Evaluate the classes, the hidden state, and the style of the sidebar.
+fn sidebar_state(props: &PageSidebarProperties) -> (Classes, bool, Option<&'static str>) {
+    let (mut classes, hidden, style) = match (props.open, props.behavior) {
+        (true, _) => (classes!["pf-m-expanded"], false, None),
+        (false, PageSidebarBehavior::Expandable) => (classes!["pf-m-collapsed"], true, None),
+        (false, PageSidebarBehavior::Collapsible) => {
+            (classes!["pf-m-collapsed"], false, Some(RAIL_STYLE))
+        }
+    };
+
+    classes.push("pf-c-page__sidebar");
+
+    (classes, hidden, style)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn state(open: bool, behavior: PageSidebarBehavior) -> (String, bool, bool) {
+        let (classes, hidden, style) =
+            sidebar_state(&yew::props!(PageSidebarProperties { open, behavior }));
+        (classes.to_string(), hidden, style.is_some())
+    }
+
+    #[test]
+    fn test_expandable() {
+        let behavior = PageSidebarBehavior::Expandable;
+        assert_eq!(
+            state(true, behavior),
+            ("pf-m-expanded pf-c-page__sidebar".into(), false, false)
+        );
+        assert_eq!(
+            state(false, behavior),
+            ("pf-m-collapsed pf-c-page__sidebar".into(), true, false)
+        );
+    }
+
+    #[test]
+    fn test_collapsible() {
+        let behavior = PageSidebarBehavior::Collapsible;
+        assert_eq!(
+            state(true, behavior),
+            ("pf-m-expanded pf-c-page__sidebar".into(), false, false)
+        );
+        // still visible, as a narrow rail
+        assert_eq!(
+            state(false, behavior),
+            ("pf-m-collapsed pf-c-page__sidebar".into(), false, true)
+        );
+    }
+}
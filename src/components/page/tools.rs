@@ -0,0 +1,125 @@
+use crate::{ExtendClasses, Visibility, WithBreakpoints};
+use yew::prelude::*;
+
+/// Properties for [`PageHeaderToolsGroup`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct PageHeaderToolsGroupProperties {
+    #[prop_or_default]
+    pub children: Children,
+    /// The visibility of the group, e.g. hiding it on small screens.
+    #[prop_or_default]
+    pub visibility: WithBreakpoints<Visibility>,
+}
+
+/// A group of tools in the header of a [`Page`](crate::prelude::Page).
+///
+/// Groups are placed in the `tools` of the page, and contain [`PageHeaderToolsItem`]s.
+///
+/// ## Properties
+///
+/// Defined by [`PageHeaderToolsGroupProperties`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///     html!(
+///         <PageHeaderToolsGroup visibility={[Visibility::Hidden.all(), Visibility::Visible.lg()]}>
+///             <PageHeaderToolsItem>
+///                 <Button variant={ButtonVariant::Plain} icon={Icon::Cog} />
+///             </PageHeaderToolsItem>
+///         </PageHeaderToolsGroup>
+///     )
+/// }
+/// ```
+#[function_component(PageHeaderToolsGroup)]
+pub fn page_header_tools_group(props: &PageHeaderToolsGroupProperties) -> Html {
+    html!(
+        <div class={group_classes(props)}>
+            { for props.children.iter() }
+        </div>
+    )
+}
+
+/// Properties for [`PageHeaderToolsItem`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct PageHeaderToolsItemProperties {
+    #[prop_or_default]
+    pub children: Children,
+    /// The visibility of the item, e.g. hiding it on small screens.
+    #[prop_or_default]
+    pub visibility: WithBreakpoints<Visibility>,
+    /// Mark the item as selected, e.g. when its tool is active.
+    #[prop_or_default]
+    pub selected: bool,
+}
+
+/// A single tool in a [`PageHeaderToolsGroup`].
+///
+/// ## Properties
+///
+/// Defined by [`PageHeaderToolsItemProperties`].
+#[function_component(PageHeaderToolsItem)]
+pub fn page_header_tools_item(props: &PageHeaderToolsItemProperties) -> Html {
+    html!(
+        <div class={item_classes(props)}>
+            { for props.children.iter() }
+        </div>
+    )
+}
+
+fn group_classes(props: &PageHeaderToolsGroupProperties) -> Classes {
+    let mut class = classes!("pf-c-page__header-tools-group");
+    class.extend_from(&props.visibility);
+    class
+}
+
+fn item_classes(props: &PageHeaderToolsItemProperties) -> Classes {
+    let mut class = classes!("pf-c-page__header-tools-item");
+    class.extend_from(&props.visibility);
+
+    if props.selected {
+        class.push("pf-m-selected");
+    }
+
+    class
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::WithBreakpointExt;
+
+    #[test]
+    fn test_group() {
+        let props = yew::props!(PageHeaderToolsGroupProperties {
+            visibility: Visibility::Hidden.lg(),
+        });
+        assert_eq!(
+            group_classes(&props).to_string(),
+            "pf-c-page__header-tools-group pf-m-hidden-on-lg"
+        );
+    }
+
+    #[test]
+    fn test_item() {
+        let props = yew::props!(PageHeaderToolsItemProperties {});
+        assert_eq!(
+            item_classes(&props).to_string(),
+            "pf-c-page__header-tools-item"
+        );
+
+        let props = yew::props!(PageHeaderToolsItemProperties {
+            visibility: [Visibility::Hidden.all(), Visibility::Visible.md()],
+            selected: true,
+        });
+        assert_eq!(
+            item_classes(&props).to_string(),
+            "pf-c-page__header-tools-item pf-m-hidden pf-m-visible-on-md pf-m-selected"
+        );
+    }
+}
@@ -71,11 +71,8 @@ pub fn pagination(props: &PaginationProperties) -> Html {
         .map(|m| (m as f64 / props.selected_choice as f64).ceil() as usize);
     let current_page = (props.offset as f64 / props.selected_choice as f64).ceil() as usize;
 
-    let is_last_page = if let Some(max) = props.total_entries {
-        props.offset + props.selected_choice > max
-    } else {
-        false
-    };
+    let (is_first_page, is_last_page) =
+        page_boundaries(props.total_entries, props.offset, props.selected_choice);
 
     let total_entries = props
         .total_entries
@@ -252,7 +249,7 @@ pub fn pagination(props: &PaginationProperties) -> Html {
                     <Button
                         variant={ButtonVariant::Plain}
                         onclick={onnavigation.reform(|_|Navigation::First)}
-                        disabled={ props.offset == 0 }
+                        disabled={is_first_page}
                         aria_label="Go to first page"
                     >
                       { Icon::AngleDoubleLeft }
@@ -263,7 +260,7 @@ pub fn pagination(props: &PaginationProperties) -> Html {
                         aria_label="Go to previous page"
                         variant={ButtonVariant::Plain}
                         onclick={onnavigation.reform(|_|Navigation::Previous)}
-                        disabled={ props.offset == 0 }
+                        disabled={is_first_page}
                     >
                        { Icon::AngleLeft }
                     </Button>
@@ -286,7 +283,7 @@ pub fn pagination(props: &PaginationProperties) -> Html {
                         aria_label="Go to next page"
                         variant={ButtonVariant::Plain}
                         onclick={onnavigation.reform(|_|Navigation::Next)}
-                        disabled={max_page.map_or_else(|| false, |m| current_page >= m)}
+                        disabled={is_last_page}
                     >
                         { Icon::AngleRight }
                     </Button>
@@ -305,3 +302,33 @@ pub fn pagination(props: &PaginationProperties) -> Html {
         </div>
     )
 }
+
+/// Check if the current page is the first, and if it is the last page.
+///
+/// Without knowing the total number of entries, no page is the last page.
+fn page_boundaries(total_entries: Option<usize>, offset: usize, limit: usize) -> (bool, bool) {
+    let first = offset == 0;
+    let last = total_entries.map_or(false, |total| offset + limit >= total);
+    (first, last)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_page_boundaries() {
+        // first of three pages
+        assert_eq!(page_boundaries(Some(25), 0, 10), (true, false));
+        assert_eq!(page_boundaries(Some(25), 10, 10), (false, false));
+        // last, partial, page
+        assert_eq!(page_boundaries(Some(25), 20, 10), (false, true));
+        // last, full, page
+        assert_eq!(page_boundaries(Some(20), 10, 10), (false, true));
+        // a single page
+        assert_eq!(page_boundaries(Some(5), 0, 10), (true, true));
+        assert_eq!(page_boundaries(Some(0), 0, 10), (true, true));
+        // unknown total
+        assert_eq!(page_boundaries(None, 100, 10), (false, false));
+    }
+}
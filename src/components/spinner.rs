@@ -44,6 +44,18 @@ pub struct SpinnerProperties {
 /// ## Properties
 ///
 /// Defined by [`SpinnerProperties`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///     html!(<Spinner size={SpinnerSize::Lg} aria_label="Loading items" />)
+/// }
+/// ```
 pub struct Spinner;
 
 impl Component for Spinner {
@@ -55,25 +67,76 @@ impl Component for Spinner {
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let mut classes = Classes::from("pf-c-spinner");
-        classes.extend(ctx.props().size.as_classes());
-
-        let style = if let SpinnerSize::Custom(diameter) = &ctx.props().size {
-            format!("--pf-c-spinner--diameter: {};", diameter)
-        } else {
-            String::new()
-        };
-
-        html! (
-            <svg
-                class={classes}
-                role="progressbar"
-                viewBox="0 0 100 100"
-                aria-label={ ctx.props().aria_label.clone() }
-                { style }
-            >
-                <circle class="pf-c-spinner__path" cx="50" cy="50" r="45" fill="none" />
-            </svg>
-        )
+        render_spinner(ctx.props())
+    }
+}
+
+fn render_spinner(props: &SpinnerProperties) -> Html {
+    let mut classes = Classes::from("pf-c-spinner");
+    classes.extend(props.size.as_classes());
+
+    let style = if let SpinnerSize::Custom(diameter) = &props.size {
+        format!("--pf-c-spinner--diameter: {};", diameter)
+    } else {
+        String::new()
+    };
+
+    html! (
+        <svg
+            class={classes}
+            role="progressbar"
+            viewBox="0 0 100 100"
+            aria-label={ props.aria_label.clone() }
+            aria-valuetext={ props.aria_label.clone() }
+            { style }
+        >
+            <circle class="pf-c-spinner__path" cx="50" cy="50" r="45" fill="none" />
+        </svg>
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use yew::virtual_dom::VTag;
+
+    fn spinner(size: SpinnerSize) -> Box<VTag> {
+        match render_spinner(&yew::props!(SpinnerProperties { size })) {
+            Html::VTag(tag) => tag,
+            other => panic!("expected the spinner: {other:?}"),
+        }
+    }
+
+    fn attribute<'a>(tag: &'a VTag, name: &str) -> Option<&'a str> {
+        tag.attributes
+            .iter()
+            .find_map(|(key, value)| (key == name).then_some(value))
+    }
+
+    #[test]
+    fn test_size() {
+        for (size, expected) in [
+            (SpinnerSize::None, "pf-c-spinner"),
+            (SpinnerSize::Sm, "pf-c-spinner pf-m-sm"),
+            (SpinnerSize::Md, "pf-c-spinner pf-m-md"),
+            (SpinnerSize::Lg, "pf-c-spinner pf-m-lg"),
+            (SpinnerSize::Xl, "pf-c-spinner pf-m-xl"),
+            (SpinnerSize::Custom("3em".into()), "pf-c-spinner"),
+        ] {
+            assert_eq!(attribute(&spinner(size), "class"), Some(expected));
+        }
+
+        assert_eq!(
+            attribute(&spinner(SpinnerSize::Custom("3em".into())), "style"),
+            Some("--pf-c-spinner--diameter: 3em;")
+        );
+    }
+
+    #[test]
+    fn test_aria() {
+        let tag = spinner(SpinnerSize::Md);
+        assert_eq!(attribute(&tag, "role"), Some("progressbar"));
+        assert_eq!(attribute(&tag, "aria-label"), Some("Loading..."));
+        assert_eq!(attribute(&tag, "aria-valuetext"), Some("Loading..."));
     }
 }
@@ -123,6 +123,7 @@ impl Span {
             modifiers: Vec::new(),
         }
     }
+    /// Span all remaining columns.
     pub fn max(html: Html) -> Self {
         Self {
             cols: usize::MAX,
@@ -342,9 +343,7 @@ where
                 <tr>
                     <td class="pf-c-table__toggle">
                         <button class={classes} onclick={onclick} aria-expanded={aria_expanded}>
-                            <div class="pf-c-table__toggle-icon">
-                                { Icon::AngleDown }
-                            </div>
+                            { render_toggle_icon(expanded) }
                         </button>
                     </td>
 
@@ -417,3 +416,20 @@ where
         )
     }
 }
+
+/// Render the icon of an expand toggle.
+///
+/// The rotation is set explicitly, so that the icon reflects the state without relying on the
+/// `pf-m-expanded` class of the button.
+fn render_toggle_icon(expanded: bool) -> Html {
+    let style = match expanded {
+        true => "transform: rotate(0);",
+        false => "transform: rotate(-90deg);",
+    };
+
+    html!(
+        <div class="pf-c-table__toggle-icon" {style}>
+            { Icon::AngleDown }
+        </div>
+    )
+}
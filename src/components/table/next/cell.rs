@@ -7,6 +7,8 @@ pub struct Cell {
     pub content: Html,
     pub center: bool,
     pub text_modifier: Option<TextModifier>,
+    /// Additional classes of the cell.
+    pub class: Classes,
 }
 
 impl Cell {
@@ -26,6 +28,12 @@ impl Cell {
         self.text_modifier = text_modifier.into();
         self
     }
+
+    /// Add classes to the cell.
+    pub fn class(mut self, class: impl Into<Classes>) -> Self {
+        self.class.extend(class.into());
+        self
+    }
 }
 
 impl From<Html> for Cell {
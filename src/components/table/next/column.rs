@@ -1,9 +1,10 @@
-use crate::{AsClasses, ExtendClasses};
+use super::drag::DragHandlers;
+use crate::{AsClasses, ExtendClasses, Icon, Visibility, WithBreakpoints};
 use std::fmt::Debug;
 use yew::prelude::*;
 
 /// Properties for [`TableColumn`]
-#[derive(Debug, PartialEq, Properties)]
+#[derive(Clone, Debug, PartialEq, Properties)]
 pub struct TableColumnProperties<C>
 where
     C: Clone + Eq + 'static,
@@ -12,14 +13,193 @@ where
     pub index: C,
     #[prop_or_default]
     pub label: Option<String>,
+    /// Content of the header cell, like icons or tooltips, shown instead of the [`Self::label`].
+    ///
+    /// The label is still used as [`Self::data_label`], unless that is set explicitly.
+    #[prop_or_default]
+    pub children: Children,
+    /// The label of the column, shown before each cell on small screens.
+    ///
+    /// Defaults to [`Self::label`].
+    #[prop_or_default]
+    pub data_label: Option<String>,
     #[prop_or_default]
     pub center: bool,
+    /// The alignment of the text of the column, for the header and all cells.
+    ///
+    /// Cells which are centered themselves, using [`Cell::center`](super::Cell::center), are
+    /// still centered.
+    #[prop_or_default]
+    pub text_align: Option<TextAlign>,
+    /// The width of the column.
+    ///
+    /// Percentage widths are relative to the table, the widths of all columns should not sum up
+    /// to more than 100%.
     #[prop_or_default]
     pub width: ColumnWidth,
+    /// Additional modifiers of the column header.
+    #[prop_or_default]
+    pub modifiers: Vec<TableColumnModifier>,
+    /// The visibility of the column, for the header and all cells.
+    ///
+    /// This allows hiding less important columns on smaller screens, e.g. a column only visible
+    /// on large screens: `[Visibility::Hidden.all(), Visibility::Visible.lg()]`.
+    #[prop_or_default]
+    pub visibility: WithBreakpoints<Visibility>,
+
+    /// Allow sorting by this column.
+    ///
+    /// The table doesn't sort the entries itself, it only reports the request through
+    /// [`Self::onsort`]. The current state must be provided using
+    /// [`TableHeaderProperties::sortby`](super::TableHeaderProperties::sortby).
+    #[prop_or_default]
+    pub sortable: bool,
+    /// Called when the user requests sorting by this column.
+    #[prop_or_default]
+    pub onsort: Callback<(C, SortDirection)>,
+
+    /// Cells of this column may toggle compound expandable content.
+    ///
+    /// Cells are rendered using [`TableEntryRenderer::render_compound_cell`](super::TableEntryRenderer::render_compound_cell).
+    /// This has no effect when the table uses an expandable [`TableMode`](crate::prelude::TableMode).
+    #[prop_or_default]
+    pub compound: bool,
 
     #[doc(hidden)]
     #[prop_or_default]
     pub(crate) first_tree_column: bool,
+
+    /// The current sort state, injected by the header.
+    #[doc(hidden)]
+    #[prop_or_default]
+    pub(crate) sortby: Option<TableHeaderSortBy<C>>,
+
+    /// The sticky state, injected by the header.
+    #[doc(hidden)]
+    #[prop_or_default]
+    pub(crate) sticky: Option<StickyColumn>,
+
+    /// The drag handlers for reordering columns, injected by the header.
+    #[doc(hidden)]
+    #[prop_or_default]
+    pub(crate) drag: DragHandlers,
+}
+
+/// The state of a sticky column.
+#[doc(hidden)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StickyColumn {
+    /// The offset from the left, in percent, if known.
+    left: Option<u16>,
+    /// The last sticky column, which carries the border.
+    last: bool,
+}
+
+impl StickyColumn {
+    /// Apply the classes and return the style of a sticky cell.
+    pub(crate) fn apply(&self, class: &mut Classes) -> Option<String> {
+        class.push(classes!("pf-m-sticky-column"));
+        if self.last {
+            class.push(classes!("pf-m-border-right"));
+        }
+        self.left
+            .map(|left| format!("--pf-c-table__sticky-column--Left: {left}%;"))
+    }
+}
+
+/// Evaluate the state of the first `count` columns, following the `controls` columns.
+///
+/// The offset of a column can only be computed if all previous columns have a percentage width,
+/// which excludes the control columns (toggle, selection, ...).
+pub(crate) fn sticky_columns<'c, C>(
+    columns: impl IntoIterator<Item = &'c TableColumnProperties<C>>,
+    count: usize,
+    controls: usize,
+) -> Vec<StickyColumn>
+where
+    C: Clone + Eq + 'static,
+{
+    let mut left = (controls == 0).then_some(0);
+    let mut result: Vec<StickyColumn> = columns
+        .into_iter()
+        .take(count)
+        .map(|column| {
+            let sticky = StickyColumn { left, last: false };
+            left = match (left, &column.width) {
+                (Some(left), ColumnWidth::Percent(p)) => Some(left + round(*p)),
+                _ => None,
+            };
+            sticky
+        })
+        .collect();
+
+    if let Some(last) = result.last_mut() {
+        last.last = true;
+    }
+
+    result
+}
+
+/// The direction of sorting.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// Get the opposite direction.
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+
+    fn as_aria(&self) -> &'static str {
+        match self {
+            Self::Ascending => "ascending",
+            Self::Descending => "descending",
+        }
+    }
+}
+
+/// The column, and direction, a table is sorted by.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableHeaderSortBy<C> {
+    pub index: C,
+    pub direction: SortDirection,
+}
+
+impl<C> TableHeaderSortBy<C> {
+    pub fn new(index: C, direction: SortDirection) -> Self {
+        Self { index, direction }
+    }
+
+    pub fn ascending(index: C) -> Self {
+        Self::new(index, SortDirection::Ascending)
+    }
+
+    pub fn descending(index: C) -> Self {
+        Self::new(index, SortDirection::Descending)
+    }
+}
+
+/// Get the direction a column is currently sorted by, if it is the sorted column.
+fn active_direction<C: PartialEq>(
+    sortby: &Option<TableHeaderSortBy<C>>,
+    index: &C,
+) -> Option<SortDirection> {
+    sortby
+        .as_ref()
+        .filter(|sortby| &sortby.index == index)
+        .map(|sortby| sortby.direction)
+}
+
+/// Get the next direction when the user clicks on a sortable column.
+fn next_direction(active: Option<SortDirection>) -> SortDirection {
+    active.map_or(SortDirection::Ascending, SortDirection::toggle)
 }
 
 #[derive(Copy, Clone, Default, Eq, PartialEq, Debug)]
@@ -60,13 +240,214 @@ impl AsClasses for ColumnWidth {
     }
 }
 
+/// The alignment of text in a column.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl AsClasses for TextAlign {
+    fn extend_classes(&self, classes: &mut Classes) {
+        match self {
+            Self::Left => {}
+            Self::Center => classes.push(classes!("pf-m-center")),
+            Self::Right => classes.push(classes!("pf-m-right")),
+        }
+    }
+}
+
+/// Modifiers of a table column header.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TableColumnModifier {
+    Wrap,
+    NoWrap,
+    Truncate,
+    BreakWord,
+    BorderRight,
+    BorderLeft,
+}
+
+impl AsClasses for TableColumnModifier {
+    fn extend_classes(&self, classes: &mut Classes) {
+        match self {
+            Self::Wrap => classes.push(classes!("pf-m-wrap")),
+            Self::NoWrap => classes.push(classes!("pf-m-nowrap")),
+            Self::Truncate => classes.push(classes!("pf-m-truncate")),
+            Self::BreakWord => classes.push(classes!("pf-m-break-word")),
+            Self::BorderRight => classes.push(classes!("pf-m-border-right")),
+            Self::BorderLeft => classes.push(classes!("pf-m-border-left")),
+        }
+    }
+}
+
 /// The Table Column component.
 ///
 /// ## Properties
 ///
 /// Define by [`TableColumnProperties`].
+///
+/// ## Example
+///
+/// A sortable column, sorting a `Vec` backed model:
+///
+/// ```rust
+/// use patternfly_yew::next::{
+///     use_table_data, Cell, CellContext, MemoizedTableModel, SortDirection, Table, TableColumn,
+///     TableEntryRenderer, TableHeader, TableHeaderSortBy, UseTableData,
+/// };
+/// use std::rc::Rc;
+/// use yew::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq)]
+/// enum Column {
+///     Name,
+/// }
+///
+/// #[derive(Clone, PartialEq)]
+/// struct Item {
+///     name: String,
+/// }
+///
+/// impl TableEntryRenderer<Column> for Item {
+///     fn render_cell(&self, context: &CellContext<'_, Column>) -> Cell {
+///         match context.column {
+///             Column::Name => html!(&self.name),
+///         }
+///         .into()
+///     }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///     let sortby = use_state_eq(|| Some(TableHeaderSortBy::ascending(Column::Name)));
+///
+///     let entries = use_memo(
+///         |sortby| {
+///             let mut entries = vec![
+///                 Item { name: "Foo".into() },
+///                 Item { name: "Bar".into() },
+///             ];
+///             if let Some(sortby) = sortby {
+///                 entries.sort_by(|a, b| a.name.cmp(&b.name));
+///                 if sortby.direction == SortDirection::Descending {
+///                     entries.reverse();
+///                 }
+///             }
+///             MemoizedTableModel::new(Rc::new(entries))
+///         },
+///         (*sortby).clone(),
+///     );
+///     let (entries, onexpand) = use_table_data((*entries).clone());
+///
+///     let onsort = {
+///         let sortby = sortby.clone();
+///         Callback::from(move |(index, direction)| {
+///             sortby.set(Some(TableHeaderSortBy::new(index, direction)))
+///         })
+///     };
+///
+///     let header = html_nested!(
+///         <TableHeader<Column> sortby={(*sortby).clone()}>
+///             <TableColumn<Column> label="Name" index={Column::Name} sortable=true {onsort} />
+///         </TableHeader<Column>>
+///     );
+///
+///     html!(
+///         <Table<Column, UseTableData<Column, MemoizedTableModel<Item>>>
+///             {header} {entries} {onexpand}
+///         />
+///     )
+/// }
+/// ```
+///
+/// Columns splitting the width of the table 20/80:
+///
+/// ```rust
+/// use patternfly_yew::next::{ColumnWidth, TableColumn, TableColumnModifier, TableHeader};
+/// use yew::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq)]
+/// enum Column {
+///     Name,
+///     Description,
+/// }
+///
+/// let header = html_nested!(
+///     <TableHeader<Column>>
+///         <TableColumn<Column> label="Name" index={Column::Name} width={ColumnWidth::Percent(20)} />
+///         <TableColumn<Column>
+///             label="Description"
+///             index={Column::Description}
+///             width={ColumnWidth::Percent(80)}
+///             modifiers={vec![TableColumnModifier::Truncate]}
+///         />
+///     </TableHeader<Column>>
+/// );
+/// ```
 #[function_component(TableColumn)]
 pub fn table_column<K>(props: &TableColumnProperties<K>) -> Html
+where
+    K: Clone + Eq + 'static,
+{
+    let mut class = header_classes(props);
+
+    let style = props
+        .sticky
+        .as_ref()
+        .and_then(|sticky| sticky.apply(&mut class));
+
+    if props.sortable {
+        return render_sortable(props, class, style);
+    }
+
+    let drag = props.drag.clone();
+
+    match header_content(props) {
+        None => html! (
+            <th
+                {class}
+                {style}
+                draggable={drag.draggable}
+                ondragstart={drag.ondragstart}
+                ondragover={drag.ondragover}
+                ondrop={drag.ondrop}
+                ondragend={drag.ondragend}
+            ></th>
+        ),
+        Some(content) => html! (
+            <th
+                {class}
+                {style}
+                scope="col"
+                role="columnheader"
+                draggable={drag.draggable}
+                ondragstart={drag.ondragstart}
+                ondragover={drag.ondragover}
+                ondrop={drag.ondrop}
+                ondragend={drag.ondragend}
+            >
+                { content }
+            </th>
+        ),
+    }
+}
+
+/// The content of the header cell, preferring the children over the label.
+pub(super) fn header_content<K>(props: &TableColumnProperties<K>) -> Option<Html>
+where
+    K: Clone + Eq + 'static,
+{
+    match props.children.is_empty() {
+        false => Some(html!({ for props.children.iter() })),
+        true => props.label.as_ref().map(|label| html!(label)),
+    }
+}
+
+/// The classes of the header cell, not including the sticky state.
+pub(super) fn header_classes<K>(props: &TableColumnProperties<K>) -> Classes
 where
     K: Clone + Eq + 'static,
 {
@@ -78,16 +459,70 @@ where
 
     if props.center {
         class.push(classes!("pf-m-center"));
+    } else {
+        class.extend_from(&props.text_align);
     }
 
     class.extend_from(&props.width);
+    class.extend_from(&props.modifiers);
+    class.extend_from(&props.visibility);
 
-    match &props.label {
-        None => html! (<th></th>),
-        Some(label) => html! (
-            <th {class} scope="col" role="columnheader">{ &label }</th>
-        ),
+    class
+}
+
+fn render_sortable<K>(
+    props: &TableColumnProperties<K>,
+    mut class: Classes,
+    style: Option<String>,
+) -> Html
+where
+    K: Clone + Eq + 'static,
+{
+    class.push(classes!("pf-c-table__sort"));
+
+    let active = active_direction(&props.sortby, &props.index);
+    if active.is_some() {
+        class.push(classes!("pf-m-selected"));
     }
+
+    let aria_sort = active.map_or("none", |direction| direction.as_aria());
+
+    let icon = match active {
+        None => Icon::ArrowsAltV,
+        Some(SortDirection::Ascending) => Icon::LongArrowAltUp,
+        Some(SortDirection::Descending) => Icon::LongArrowAltDown,
+    };
+
+    let onclick = {
+        let index = props.index.clone();
+        props
+            .onsort
+            .reform(move |_: MouseEvent| (index.clone(), next_direction(active)))
+    };
+
+    let drag = props.drag.clone();
+
+    html! (
+        <th
+            {class}
+            {style}
+            scope="col"
+            role="columnheader"
+            aria-sort={aria_sort}
+            draggable={drag.draggable}
+            ondragstart={drag.ondragstart}
+            ondragover={drag.ondragover}
+            ondrop={drag.ondrop}
+            ondragend={drag.ondragend}
+        >
+            <button class="pf-c-table__button" {onclick}>
+                <div class="pf-c-table__button-content">
+                    <span class="pf-c-table__text">{ header_content(props).unwrap_or_default() }</span>
+                    <span class="pf-c-table__sort-indicator">{ icon }</span>
+                </div>
+            </button>
+        </th>
+    )
 }
 
 #[cfg(test)]
@@ -106,4 +541,103 @@ mod test {
         assert_eq!(round(100), 90);
         assert_eq!(round(200), 90);
     }
+
+    #[test]
+    fn test_modifiers() {
+        let mut class = Classes::new();
+        class.extend_from(&ColumnWidth::Percent(20));
+        class.extend_from(&vec![
+            TableColumnModifier::Truncate,
+            TableColumnModifier::BorderRight,
+        ]);
+        assert_eq!(
+            class.to_string(),
+            "pf-m-width-20 pf-m-truncate pf-m-border-right"
+        );
+    }
+
+    #[test]
+    fn test_sticky_columns() {
+        let column = |width| {
+            yew::props!(TableColumnProperties<&str> {
+                index: "column",
+                width,
+            })
+        };
+        let columns = [
+            column(ColumnWidth::Percent(20)),
+            column(ColumnWidth::Percent(30)),
+            column(ColumnWidth::Default),
+            column(ColumnWidth::Default),
+        ];
+
+        let sticky = sticky_columns(&columns, 3, 0);
+        assert_eq!(
+            sticky,
+            vec![
+                StickyColumn {
+                    left: Some(0),
+                    last: false
+                },
+                StickyColumn {
+                    left: Some(20),
+                    last: false
+                },
+                StickyColumn {
+                    left: Some(50),
+                    last: true
+                },
+            ]
+        );
+
+        // unknown widths stop the computation of offsets
+        let columns = [
+            column(ColumnWidth::FitContent),
+            column(ColumnWidth::Default),
+        ];
+        assert_eq!(sticky_columns(&columns, 2, 0)[1].left, None);
+        assert!(sticky_columns(&columns, 0, 0).is_empty());
+
+        // the width of a control column is unknown, so is the offset of all data columns
+        let columns = [
+            column(ColumnWidth::Percent(20)),
+            column(ColumnWidth::Percent(30)),
+        ];
+        assert_eq!(
+            sticky_columns(&columns, 2, 1),
+            vec![
+                StickyColumn {
+                    left: None,
+                    last: false
+                },
+                StickyColumn {
+                    left: None,
+                    last: true
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sort_direction() {
+        let sortby = Some(TableHeaderSortBy::ascending("name"));
+
+        assert_eq!(
+            active_direction(&sortby, &"name"),
+            Some(SortDirection::Ascending)
+        );
+        assert_eq!(active_direction(&sortby, &"age"), None);
+        assert_eq!(active_direction(&None, &"name"), None);
+
+        // clicking an unsorted column starts ascending, clicking a sorted one toggles
+        assert_eq!(next_direction(None), SortDirection::Ascending);
+        assert_eq!(
+            next_direction(Some(SortDirection::Ascending)),
+            SortDirection::Descending
+        );
+        assert_eq!(
+            next_direction(Some(SortDirection::Descending)),
+            SortDirection::Ascending
+        );
+    }
 }
@@ -0,0 +1,143 @@
+//! Reordering rows and columns using drag and drop.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// The state of a drag operation, shared by all rows, or all columns, of a table.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DragState(Rc<Cell<Option<usize>>>);
+
+impl PartialEq for DragState {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl DragState {
+    /// Start dragging the row with the index `from`.
+    pub(crate) fn start(&self, from: usize) {
+        self.0.set(Some(from));
+    }
+
+    /// Drop onto the row with the index `to`, returning the reorder request, if any.
+    pub(crate) fn drop(&self, to: usize) -> Option<(usize, usize)> {
+        let from = self.0.take()?;
+        (from != to).then_some((from, to))
+    }
+
+    /// Drop onto the index `to`, emitting the reorder request, if any.
+    pub(crate) fn drop_on(&self, to: usize, onreorder: &Callback<(usize, usize)>) {
+        if let Some(reorder) = self.drop(to) {
+            onreorder.emit(reorder);
+        }
+    }
+
+    /// End the drag operation, without dropping.
+    pub(crate) fn end(&self) {
+        self.0.set(None);
+    }
+
+    pub(crate) fn is_dragging(&self) -> bool {
+        self.0.get().is_some()
+    }
+}
+
+/// The event handlers of a row or column, which are only present when it is draggable.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct DragHandlers {
+    pub draggable: Option<&'static str>,
+    pub ondragstart: Option<Callback<DragEvent>>,
+    pub ondragover: Option<Callback<DragEvent>>,
+    pub ondrop: Option<Callback<DragEvent>>,
+    pub ondragend: Option<Callback<DragEvent>>,
+}
+
+impl DragHandlers {
+    pub(crate) fn new(
+        state: &DragState,
+        index: usize,
+        onreorder: &Callback<(usize, usize)>,
+    ) -> Self {
+        let ondragstart = {
+            let state = state.clone();
+            Callback::from(move |event: DragEvent| {
+                if let Some(data) = event.data_transfer() {
+                    data.set_effect_allowed("move");
+                    // some browsers only start dragging with data
+                    let _ = data.set_data("text/plain", &index.to_string());
+                }
+                state.start(index);
+            })
+        };
+
+        let ondragover = {
+            let state = state.clone();
+            Callback::from(move |event: DragEvent| {
+                if state.is_dragging() {
+                    // allow dropping
+                    event.prevent_default();
+                }
+            })
+        };
+
+        let ondrop = {
+            let state = state.clone();
+            let onreorder = onreorder.clone();
+            Callback::from(move |event: DragEvent| {
+                event.prevent_default();
+                state.drop_on(index, &onreorder);
+            })
+        };
+
+        let ondragend = {
+            let state = state.clone();
+            Callback::from(move |_: DragEvent| state.end())
+        };
+
+        Self {
+            draggable: Some("true"),
+            ondragstart: Some(ondragstart),
+            ondragover: Some(ondragover),
+            ondrop: Some(ondrop),
+            ondragend: Some(ondragend),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_drop() {
+        let state = DragState::default();
+        let rows: Vec<_> = (0..3).map(|_| state.clone()).collect();
+
+        rows[0].start(0);
+        assert!(state.is_dragging());
+        assert_eq!(rows[2].drop(2), Some((0, 2)));
+        assert!(!state.is_dragging());
+
+        // dropping without dragging
+        assert_eq!(rows[1].drop(1), None);
+    }
+
+    #[test]
+    fn test_drop_same_row() {
+        let state = DragState::default();
+
+        state.start(1);
+        assert_eq!(state.drop(1), None);
+        assert!(!state.is_dragging());
+    }
+
+    #[test]
+    fn test_cancel() {
+        let state = DragState::default();
+
+        state.start(1);
+        state.end();
+        assert_eq!(state.drop(2), None);
+    }
+}
@@ -0,0 +1,254 @@
+//! Keyboard navigation of a table in grid mode.
+//!
+//! Implements a roving `tabindex`: only a single cell of the table can be reached using the tab
+//! key, the other cells are reached using the arrow keys, `Home`, and `End`.
+
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+
+const CELLS: &str = ":scope > thead > tr, :scope > tbody > tr";
+
+/// A navigation request, derived from a key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Navigation {
+    Up,
+    Down,
+    Left,
+    Right,
+    /// The first cell of the row.
+    Home,
+    /// The last cell of the row.
+    End,
+}
+
+impl Navigation {
+    pub(crate) fn from_key(key: &str) -> Option<Self> {
+        Some(match key {
+            "ArrowUp" => Self::Up,
+            "ArrowDown" => Self::Down,
+            "ArrowLeft" => Self::Left,
+            "ArrowRight" => Self::Right,
+            "Home" => Self::Home,
+            "End" => Self::End,
+            _ => return None,
+        })
+    }
+}
+
+/// The position of a cell in the grid.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub(crate) fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// Navigate the grid, defined by the number of cells of each row.
+    ///
+    /// Rows may have different numbers of cells (e.g. when using `colspan`), in which case the
+    /// column is limited to the last cell of the target row. Returns [`None`] if the position
+    /// doesn't change.
+    pub(crate) fn navigate(self, navigation: Navigation, rows: &[usize]) -> Option<Self> {
+        let cells = |row: usize| rows.get(row).copied().unwrap_or_default();
+        let clamp = |row: usize, col: usize| col.min(cells(row).saturating_sub(1));
+
+        let next = match navigation {
+            Navigation::Up => {
+                let row = (0..self.row).rev().find(|row| cells(*row) > 0)?;
+                Self::new(row, clamp(row, self.col))
+            }
+            Navigation::Down => {
+                let row = (self.row + 1..rows.len()).find(|row| cells(*row) > 0)?;
+                Self::new(row, clamp(row, self.col))
+            }
+            Navigation::Left => Self::new(self.row, self.col.checked_sub(1)?),
+            Navigation::Right => Self::new(self.row, clamp(self.row, self.col + 1)),
+            Navigation::Home => Self::new(self.row, 0),
+            Navigation::End => Self::new(self.row, clamp(self.row, usize::MAX)),
+        };
+
+        (next != self).then_some(next)
+    }
+}
+
+/// Collect the cells of the table, row by row.
+fn cells(table: &Element) -> Vec<Vec<Element>> {
+    let mut result = vec![];
+
+    if let Ok(rows) = table.query_selector_all(CELLS) {
+        for i in 0..rows.length() {
+            if let Some(row) = rows.item(i).and_then(|row| row.dyn_into::<Element>().ok()) {
+                let children = row.children();
+                result.push(
+                    (0..children.length())
+                        .filter_map(|i| children.item(i))
+                        .collect(),
+                );
+            }
+        }
+    }
+
+    result
+}
+
+/// Make sure that exactly one cell can be reached using the tab key.
+fn init(table: &Element) {
+    let cells = cells(table);
+    let mut active = false;
+
+    for cell in cells.iter().flatten() {
+        match cell.get_attribute("tabindex").as_deref() {
+            Some("0") if !active => active = true,
+            _ => {
+                let _ = cell.set_attribute("tabindex", "-1");
+            }
+        }
+    }
+
+    if !active {
+        if let Some(cell) = cells.iter().flatten().next() {
+            let _ = cell.set_attribute("tabindex", "0");
+        }
+    }
+}
+
+fn navigate(table: &Element, event: &KeyboardEvent) {
+    let Some(navigation) = Navigation::from_key(&event.key()) else {
+        return;
+    };
+
+    let Some(current) = event
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .and_then(|target| target.closest("td, th").ok().flatten())
+    else {
+        return;
+    };
+
+    let cells = cells(table);
+    let Some(position) = cells.iter().enumerate().find_map(|(row, cells)| {
+        cells
+            .iter()
+            .position(|cell| *cell == current)
+            .map(|col| Position::new(row, col))
+    }) else {
+        return;
+    };
+
+    let rows: Vec<usize> = cells.iter().map(Vec::len).collect();
+    if let Some(next) = position.navigate(navigation, &rows) {
+        event.prevent_default();
+
+        let next = &cells[next.row][next.col];
+        let _ = current.set_attribute("tabindex", "-1");
+        let _ = next.set_attribute("tabindex", "0");
+        if let Some(next) = next.dyn_ref::<HtmlElement>() {
+            let _ = next.focus();
+        }
+    }
+}
+
+/// Manage the focus of the table referenced by `table`, returning the handler for key events.
+#[hook]
+pub(crate) fn use_grid_focus(table: NodeRef, enabled: bool) -> Callback<KeyboardEvent> {
+    {
+        let table = table.clone();
+        // rows may change with every render
+        use_effect(move || {
+            if enabled {
+                if let Some(table) = table.cast::<Element>() {
+                    init(&table);
+                }
+            }
+        });
+    }
+
+    use_callback(
+        move |event: KeyboardEvent, enabled| {
+            if *enabled {
+                if let Some(table) = table.cast::<Element>() {
+                    navigate(&table, &event);
+                }
+            }
+        },
+        enabled,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn keys(start: Position, keys: &[&str], rows: &[usize]) -> Position {
+        keys.iter().fold(start, |position, key| {
+            let navigation = Navigation::from_key(key).unwrap();
+            position.navigate(navigation, rows).unwrap_or(position)
+        })
+    }
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(Navigation::from_key("ArrowUp"), Some(Navigation::Up));
+        assert_eq!(Navigation::from_key("End"), Some(Navigation::End));
+        assert_eq!(Navigation::from_key("Enter"), None);
+    }
+
+    #[test]
+    fn test_navigate() {
+        let rows = [3, 3, 3];
+
+        assert_eq!(
+            keys(Position::default(), &["ArrowDown", "ArrowRight"], &rows),
+            Position::new(1, 1)
+        );
+        // stop at the edges
+        assert_eq!(
+            keys(Position::default(), &["ArrowUp", "ArrowLeft"], &rows),
+            Position::default()
+        );
+        assert_eq!(
+            keys(Position::default(), &["ArrowDown"; 5], &rows),
+            Position::new(2, 0)
+        );
+        assert_eq!(Position::new(0, 2).navigate(Navigation::Right, &rows), None);
+    }
+
+    #[test]
+    fn test_home_end() {
+        let rows = [4, 4];
+
+        assert_eq!(
+            keys(Position::new(1, 1), &["End"], &rows),
+            Position::new(1, 3)
+        );
+        assert_eq!(
+            keys(Position::new(1, 3), &["Home"], &rows),
+            Position::new(1, 0)
+        );
+    }
+
+    #[test]
+    fn test_spanning_rows() {
+        // a details row, spanning all columns
+        let rows = [3, 1, 0, 3];
+
+        assert_eq!(
+            keys(Position::new(0, 2), &["ArrowDown"], &rows),
+            Position::new(1, 0)
+        );
+        // skip empty rows
+        assert_eq!(
+            keys(Position::new(1, 0), &["ArrowDown"], &rows),
+            Position::new(3, 0)
+        );
+        assert_eq!(
+            keys(Position::new(3, 2), &["ArrowUp", "ArrowUp"], &rows),
+            Position::new(0, 0)
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use yew::prelude::*;
+
+/// Properties for [`TableFooter`]
+#[derive(Debug, PartialEq, Clone, Properties)]
+pub struct TableFooterProperties {
+    /// The content of the cells, one for each column of the header.
+    ///
+    /// Missing cells are rendered empty.
+    #[prop_or_default]
+    pub children: Children,
+    #[prop_or_default]
+    pub(crate) control_columns: usize,
+    #[prop_or_default]
+    pub(crate) data_columns: usize,
+    #[prop_or_default]
+    pub(crate) actions: bool,
+}
+
+/// The Table Footer component, e.g. for showing totals.
+///
+/// The cells are aligned to the columns of the [`TableHeader`](super::TableHeader).
+///
+/// ## Properties
+///
+/// Defined by [`TableFooterProperties`].
+#[function_component(TableFooter)]
+pub fn table_footer(props: &TableFooterProperties) -> Html {
+    render_footer(props)
+}
+
+fn render_footer(props: &TableFooterProperties) -> Html {
+    let padding = props.data_columns.saturating_sub(props.children.len());
+
+    html!(
+        <tfoot role="rowgroup">
+            <tr role="row">
+                { for (0..props.control_columns).map(|_| html!(<td></td>)) }
+                { for props.children.iter().map(|cell| html!(
+                    <td role="cell">{ cell }</td>
+                )) }
+                { for (0..padding).map(|_| html!(<td></td>)) }
+                if props.actions {
+                    <td></td>
+                }
+            </tr>
+        </tfoot>
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use yew::virtual_dom::VTag;
+
+    fn cells(props: &TableFooterProperties) -> Vec<Option<String>> {
+        fn collect(node: &Html, result: &mut Vec<Option<String>>) {
+            match node {
+                Html::VTag(tag) => result.push(role(tag)),
+                Html::VList(list) => list.iter().for_each(|node| collect(node, result)),
+                _ => {}
+            }
+        }
+
+        fn role(tag: &VTag) -> Option<String> {
+            tag.attributes
+                .iter()
+                .find_map(|(name, value)| (name == "role").then(|| value.to_string()))
+        }
+
+        let row = match render_footer(props) {
+            Html::VTag(tfoot) => match tfoot.children().first() {
+                Some(Html::VTag(row)) => row.clone(),
+                other => panic!("expected the row: {other:?}"),
+            },
+            other => panic!("expected the footer: {other:?}"),
+        };
+
+        let mut result = vec![];
+        row.children()
+            .iter()
+            .for_each(|node| collect(node, &mut result));
+        result
+    }
+
+    #[test]
+    fn test_cells() {
+        let props = yew::props!(TableFooterProperties {
+            children: Children::new(vec![html!("Total"), html!("42")]),
+            control_columns: 1,
+            data_columns: 3,
+            actions: true,
+        });
+
+        let cell = Some("cell".to_string());
+        assert_eq!(cells(&props), vec![None, cell.clone(), cell, None, None]);
+    }
+}
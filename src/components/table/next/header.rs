@@ -1,7 +1,37 @@
 use std::fmt::Debug;
+use std::rc::Rc;
 use yew::prelude::*;
+use yew::virtual_dom::VChild;
 
-use super::column::TableColumn;
+use super::column::{StickyColumn, TableColumn, TableHeaderSortBy};
+use super::drag::{DragHandlers, DragState};
+use super::{render_toggle_icon, TableSelectionMode};
+
+/// A group of consecutive columns, sharing a header spanning all of them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableColumnGroup {
+    /// The label of the group, or [`None`] for columns not being part of a group.
+    pub label: Option<String>,
+    /// The number of columns of the group.
+    pub columns: usize,
+}
+
+impl TableColumnGroup {
+    pub fn new(label: impl Into<String>, columns: usize) -> Self {
+        Self {
+            label: Some(label.into()),
+            columns,
+        }
+    }
+
+    /// Columns not being part of a group.
+    pub fn ungrouped(columns: usize) -> Self {
+        Self {
+            label: None,
+            columns,
+        }
+    }
+}
 
 /// Properties for [`TableHeader`]
 #[derive(Debug, PartialEq, Clone, Properties)]
@@ -14,9 +44,49 @@ where
     #[prop_or_default]
     pub children: ChildrenWithProps<TableColumn<K>>,
     #[prop_or_default]
+    pub(crate) draggable: bool,
+    #[prop_or_default]
     pub(crate) expandable: bool,
     #[prop_or_default]
+    pub(crate) tree: bool,
+    /// Show a toggle, expanding or collapsing all rows of an expandable table.
+    ///
+    /// The request is reported through [`TableProperties::onexpandall`](super::TableProperties::onexpandall).
+    #[prop_or_default]
+    pub expandable_all: bool,
+    #[prop_or_default]
+    pub(crate) all_expanded: bool,
+    #[prop_or_default]
+    pub(crate) onexpandall: Callback<bool>,
+    #[prop_or_default]
+    pub(crate) selection: TableSelectionMode,
+    #[prop_or_default]
+    pub(crate) all_selected: bool,
+    #[prop_or_default]
+    pub(crate) favorites: bool,
+    #[prop_or_default]
+    pub(crate) onselectall: Callback<bool>,
+    #[prop_or_default]
+    pub(crate) sticky_columns: Vec<StickyColumn>,
+    #[prop_or_default]
     pub hide_actions: bool,
+    /// The column the table is currently sorted by.
+    #[prop_or_default]
+    pub sortby: Option<TableHeaderSortBy<K>>,
+    /// Groups of columns, rendered as an additional header row above the columns.
+    ///
+    /// Groups are assigned to the columns from left to right, columns not covered by any group
+    /// are left ungrouped.
+    #[prop_or_default]
+    pub groups: Vec<TableColumnGroup>,
+    /// Allow reordering columns, by dragging their header cells.
+    ///
+    /// The table doesn't reorder the columns itself, it only reports the request through
+    /// [`TableProperties::oncolumnreorder`](super::TableProperties::oncolumnreorder).
+    #[prop_or_default]
+    pub reorderable_columns: bool,
+    #[prop_or_default]
+    pub(crate) oncolumnreorder: Callback<(usize, usize)>,
 }
 
 /// The Table Header component.
@@ -29,16 +99,28 @@ pub fn table_header<K>(props: &TableHeaderProperties<K>) -> Html
 where
     K: Clone + Eq + 'static,
 {
+    let drag = use_state(DragState::default);
+
     html! (
         <thead>
 
+            { render_groups(props) }
+
             <tr role="row">
 
-                if props.expandable {
+                if props.draggable {
+                    <th></th>
+                }
+
+                { render_expand_all(props) }
+
+                { render_select_all(props) }
+
+                if props.favorites {
                     <th></th>
                 }
 
-                { for props.children.iter() }
+                { for columns(props, &drag) }
 
                 if !props.hide_actions {
                     <th></th>
@@ -49,3 +131,248 @@ where
         </thead>
     )
 }
+
+/// The columns of the header, with the state injected by the header.
+fn columns<'p, K>(
+    props: &'p TableHeaderProperties<K>,
+    drag: &'p DragState,
+) -> impl Iterator<Item = VChild<TableColumn<K>>> + 'p
+where
+    K: Clone + Eq + 'static,
+{
+    props
+        .children
+        .iter()
+        .enumerate()
+        .map(move |(i, mut column)| {
+            let column_props = Rc::make_mut(&mut column.props);
+            column_props.sortby = props.sortby.clone();
+            column_props.sticky = props.sticky_columns.get(i).cloned();
+            column_props.first_tree_column = props.tree && i == 0;
+            column_props.drag = match props.reorderable_columns {
+                true => DragHandlers::new(drag, i, &props.oncolumnreorder),
+                false => DragHandlers::default(),
+            };
+            column
+        })
+}
+
+fn render_groups<K>(props: &TableHeaderProperties<K>) -> Html
+where
+    K: Clone + Eq + 'static,
+{
+    if props.groups.is_empty() {
+        return html!();
+    }
+
+    let controls = [
+        props.draggable,
+        props.expandable,
+        props.selection != TableSelectionMode::None,
+        props.favorites,
+    ]
+    .into_iter()
+    .filter(|control| *control)
+    .count();
+
+    let mut remaining = props.children.len();
+    let mut groups = vec![];
+    for group in &props.groups {
+        let columns = group.columns.min(remaining);
+        if columns == 0 {
+            break;
+        }
+        remaining -= columns;
+        groups.push(match &group.label {
+            Some(label) => html!(
+                <th colspan={columns.to_string()} scope="colgroup" role="columnheader">
+                    { label }
+                </th>
+            ),
+            None => html!(<th colspan={columns.to_string()}></th>),
+        });
+    }
+
+    html!(
+        <tr role="row">
+            if controls > 0 {
+                <th colspan={controls.to_string()}></th>
+            }
+            { groups }
+            if remaining > 0 {
+                <th colspan={remaining.to_string()}></th>
+            }
+            if !props.hide_actions {
+                <th></th>
+            }
+        </tr>
+    )
+}
+
+fn render_expand_all<K>(props: &TableHeaderProperties<K>) -> Html
+where
+    K: Clone + Eq + 'static,
+{
+    if !props.expandable {
+        return html!();
+    }
+
+    if !props.expandable_all {
+        return html!(<th></th>);
+    }
+
+    let all_expanded = props.all_expanded;
+    let onclick = props.onexpandall.reform(move |_: MouseEvent| !all_expanded);
+
+    let mut class = classes!("pf-c-button", "pf-m-plain");
+    if all_expanded {
+        class.push(classes!("pf-m-expanded"));
+    }
+
+    html!(
+        <th class="pf-c-table__toggle">
+            <button
+                {class}
+                {onclick}
+                aria-label={match all_expanded { true => "Collapse all rows", false => "Expand all rows" }}
+                aria-expanded={all_expanded.to_string()}
+            >
+                { render_toggle_icon(all_expanded) }
+            </button>
+        </th>
+    )
+}
+
+fn render_select_all<K>(props: &TableHeaderProperties<K>) -> Html
+where
+    K: Clone + Eq + 'static,
+{
+    match props.selection {
+        TableSelectionMode::None => html!(),
+        TableSelectionMode::Single => html!(<th></th>),
+        TableSelectionMode::Multiple => {
+            let all_selected = props.all_selected;
+            let onchange = props.onselectall.reform(move |_: Event| !all_selected);
+            html!(
+                <th class="pf-c-table__check">
+                    <input
+                        type="checkbox"
+                        aria-label="Select all rows"
+                        checked={all_selected}
+                        {onchange}
+                    />
+                </th>
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn th_class(html: &Html) -> Option<String> {
+        match html {
+            Html::VTag(tag) if tag.tag() == "th" => Some(
+                tag.attributes
+                    .iter()
+                    .find_map(|(key, value)| (key == "class").then(|| value.to_string()))
+                    .unwrap_or_default(),
+            ),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_groups() {
+        let props = yew::props!(TableHeaderProperties<usize> {
+            children: ChildrenWithProps::new(
+                (0..5).map(|index| html_nested!(<TableColumn<usize> {index} />)).collect()
+            ),
+            selection: TableSelectionMode::Multiple,
+            groups: vec![
+                TableColumnGroup::ungrouped(1),
+                TableColumnGroup::new("Group", 3),
+                TableColumnGroup::new("Overflow", 5),
+            ],
+            hide_actions: true,
+        });
+
+        let row = match render_groups(&props) {
+            Html::VTag(tag) => tag,
+            _ => panic!("expected the group row"),
+        };
+
+        let spans: Vec<_> = row
+            .children()
+            .iter()
+            .flat_map(|node| match node {
+                Html::VList(list) => list.iter().collect::<Vec<_>>(),
+                node => vec![node],
+            })
+            .filter_map(|node| match node {
+                Html::VTag(tag) => tag
+                    .attributes
+                    .iter()
+                    .find_map(|(key, value)| (key == "colspan").then(|| value.to_string())),
+                _ => None,
+            })
+            .collect();
+        // select, ungrouped, group, clamped to the remaining column
+        assert_eq!(spans, vec!["1", "1", "3", "1"]);
+    }
+
+    #[test]
+    fn test_column_reorder() {
+        use std::cell::Cell;
+
+        let reordered = Rc::new(Cell::new(None));
+        let props = |reorderable_columns| {
+            let reordered = reordered.clone();
+            yew::props!(TableHeaderProperties<usize> {
+                children: ChildrenWithProps::new(
+                    (0..3).map(|index| html_nested!(<TableColumn<usize> {index} />)).collect()
+                ),
+                reorderable_columns,
+                oncolumnreorder: Callback::from(move |reorder| reordered.set(Some(reorder))),
+            })
+        };
+        let drag = DragState::default();
+
+        let props = props(false);
+        assert!(columns(&props, &drag).all(|column| column.props.drag == Default::default()));
+
+        let mut props = props;
+        props.reorderable_columns = true;
+        let draggable: Vec<_> = columns(&props, &drag)
+            .map(|column| column.props.drag.draggable)
+            .collect();
+        assert_eq!(draggable, vec![Some("true"); 3]);
+
+        // drag the last column onto the first one
+        drag.start(2);
+        drag.drop_on(0, &props.oncolumnreorder);
+        assert_eq!(reordered.get(), Some((2, 0)));
+    }
+
+    #[test]
+    fn test_expand_all() {
+        let props = |expandable, expandable_all| {
+            yew::props!(TableHeaderProperties<()> {
+                expandable,
+                expandable_all,
+            })
+        };
+
+        // the leading column is always present for expandable tables
+        assert_eq!(th_class(&render_expand_all(&props(false, true))), None);
+        assert_eq!(
+            th_class(&render_expand_all(&props(true, false))),
+            Some(String::new())
+        );
+        assert_eq!(
+            th_class(&render_expand_all(&props(true, true))),
+            Some("pf-c-table__toggle".to_string())
+        );
+    }
+}
@@ -1,17 +1,26 @@
 mod cell;
 mod column;
+mod drag;
+mod focus;
+mod footer;
 mod header;
 mod model;
 mod render;
+mod state;
 
 pub use cell::*;
 pub use column::*;
+pub use footer::*;
 pub use header::*;
 pub use model::*;
 pub use render::*;
 
-use super::{TableGridMode, TableMode};
+use super::{render_toggle_icon, TableGridMode, TableMode};
 use crate::prelude::{Dropdown, ExtendClasses, Icon, KebabToggle};
+use drag::{DragHandlers, DragState};
+use gloo_events::EventListener;
+use state::{Expansion, TableState, Uncontrolled, Viewport};
+use std::ops::Range;
 use std::rc::Rc;
 use yew::{
     prelude::*,
@@ -28,37 +37,327 @@ where
     #[prop_or_default]
     pub id: AttrValue,
 
+    /// The ARIA role of the table, overriding the role derived from its features.
+    ///
+    /// By default, trees use the role `treegrid`. Interactive tables, which are selectable,
+    /// sortable, expandable, draggable, have clickable rows or favorites, or use a grid mode,
+    /// use the role `grid`. All other tables use the role `table`, as they only display data.
+    #[prop_or_default]
+    pub role: Option<AttrValue>,
+
+    /// The caption of the table.
+    ///
+    /// This may be a plain string, or any content, like a heading with a description.
     #[prop_or_default]
-    pub caption: Option<String>,
+    pub caption: TableCaption,
+    /// A combination of modifiers of the table.
+    ///
+    /// The modifiers of the mode combine with the [`Self::compact`], [`Self::borders`], and
+    /// [`Self::expandable`] flags.
     #[prop_or_default]
     pub mode: TableMode,
+    /// Use compact rows.
+    #[prop_or_default]
+    pub compact: bool,
     /// Borders or borderless.
     ///
     /// Defaults to borders being enabled.
     #[prop_or(true)]
     pub borders: bool,
+    /// Make rows expandable, showing their details.
+    #[prop_or_default]
+    pub expandable: bool,
     #[prop_or_default]
     pub header: Option<VChild<TableHeader<C>>>,
+    /// A footer row, e.g. showing totals, aligned to the columns of the header.
+    #[prop_or_default]
+    pub footer: Option<VChild<TableFooter>>,
     #[prop_or_default]
     pub full_width_details: bool,
     pub entries: M,
 
+    /// Render the entries as a tree, see [`TableModel::children`].
+    ///
+    /// The first column of each row shows a toggle for expanding its children, reported through
+    /// [`Self::onexpand`]. This takes precedence over the expandable [`TableMode`]s.
+    #[prop_or_default]
+    pub tree: bool,
+
     /// When to switch to grid mode
+    ///
+    /// This also enables keyboard navigation between the cells of the table.
     #[prop_or_default]
     pub grid: Option<TableGridMode>,
 
     #[prop_or_default]
     pub onexpand: Callback<(M::Key, bool)>,
+
+    /// Called when the user expands or collapses all rows, using the toggle in the header.
+    ///
+    /// Requires [`TableHeaderProperties::expandable_all`].
+    #[prop_or_default]
+    pub onexpandall: Callback<bool>,
+
+    /// Expansion state is provided by the model.
+    ///
+    /// By default, the model provides the expansion state through [`TableModelEntry::expanded`],
+    /// which the parent needs to update when [`Self::onexpand`] is called. Otherwise, the table
+    /// keeps track of the expansion state itself, ignoring the state of the model. This is simpler
+    /// to use, but the state is lost when the table is re-created, and can't be changed by the
+    /// parent. [`Self::onexpand`] is still called.
+    #[prop_or(true)]
+    pub controlled: bool,
+
+    /// Allow only a single entry to be expanded.
+    ///
+    /// Before expanding an entry, [`Self::onexpand`] is called to collapse all other expanded
+    /// entries. Models which apply each change to the same state, like [`MemoryTableModel`],
+    /// need to enforce this themselves, see [`MemoryTableModel::with_single_expand`].
+    #[prop_or_default]
+    pub single_expand: bool,
+
+    /// Called when the user toggles the compound expandable content of a cell.
+    ///
+    /// Provides the column to expand, or [`None`] to collapse the entry. The current state
+    /// must be provided by the model, through [`TableModel::compound_expanded`].
+    #[prop_or_default]
+    pub oncompoundexpand: Callback<(M::Key, Option<C>)>,
+
+    /// Use alternating shading of rows.
+    ///
+    /// For expandable tables, the shading alternates between entries, covering the details row too.
+    #[prop_or_default]
+    pub striped: bool,
+
+    /// Allow reordering rows, using drag and drop.
+    ///
+    /// The table doesn't reorder the entries itself, it only reports the request through
+    /// [`Self::onreorder`]. This has no effect for a [`Self::tree`].
+    #[prop_or_default]
+    pub draggable: bool,
+
+    /// Called when the user drops a row onto another row, providing the indexes of both rows.
+    #[prop_or_default]
+    pub onreorder: Callback<(usize, usize)>,
+
+    /// Called when the user drops a column onto another column, providing the indexes of both
+    /// columns.
+    ///
+    /// The indexes are those of the columns of the header, not counting the control columns.
+    /// Columns can be reordered when the header has
+    /// [`TableHeaderProperties::reorderable_columns`] set. As cells are rendered for the
+    /// [`TableColumnProperties::index`] of their column, reordering the columns of the header
+    /// reorders the cells as well.
+    #[prop_or_default]
+    pub oncolumnreorder: Callback<(usize, usize)>,
+
+    /// Make rows clickable, highlighting them on hover.
+    #[prop_or_default]
+    pub clickable_rows: bool,
+
+    /// Called when the user clicks on a row, requires [`Self::clickable_rows`].
+    ///
+    /// Clicks on controls of the row, like toggles, checkboxes, or actions, are not reported.
+    #[prop_or_default]
+    pub onrowclick: Callback<M::Key>,
+
+    /// Allow selecting rows.
+    ///
+    /// The selection state is provided by the model, through [`TableModelEntry::selected`].
+    #[prop_or_default]
+    pub selection_mode: TableSelectionMode,
+
+    /// Called when the user selects or de-selects a row.
+    #[prop_or_default]
+    pub onselect: Callback<(M::Key, bool)>,
+
+    /// The key of the active row, highlighting it as selected.
+    ///
+    /// This allows tracking the active row of a master-detail layout, e.g. using
+    /// [`Self::onrowclick`], without adding the selection state to the model.
+    #[prop_or_default]
+    pub active: Option<M::Key>,
+
+    /// Called when the user selects or de-selects all rows, using the checkbox in the header.
+    #[prop_or_default]
+    pub onselectall: Callback<bool>,
+
+    /// Show a column for marking rows as favorites.
+    ///
+    /// The state is provided by the model, through [`TableModelEntry::favorite`].
+    #[prop_or_default]
+    pub favorites: bool,
+
+    /// Called when the user marks or un-marks a row as favorite.
+    #[prop_or_default]
+    pub onfavorite: Callback<(M::Key, bool)>,
+
+    /// Content to show when the table has no entries, typically an [`EmptyState`](crate::prelude::EmptyState).
+    #[prop_or_default]
+    pub empty: Option<Html>,
+
+    /// Show skeleton rows instead of the entries, while loading data.
+    #[prop_or_default]
+    pub loading: bool,
+
+    /// The number of skeleton rows shown while loading.
+    #[prop_or(3)]
+    pub skeleton_rows: usize,
+
+    /// The number of leading data columns which stay visible when scrolling horizontally.
+    ///
+    /// Offsets of the columns can only be computed when the widths of the previous columns are
+    /// set using [`ColumnWidth::Percent`], and there are no control columns, like the selection or
+    /// toggle columns.
+    #[prop_or_default]
+    pub sticky_columns: usize,
+
+    /// Only render the rows within the visible scroll window, plus a buffer.
+    ///
+    /// The table gets wrapped in a scrolling container, which fills the height of its parent.
+    /// Rows outside the window are replaced by spacers, which requires all rows to have the same,
+    /// fixed height of [`Self::row_height`]. Only applies to tables which are neither a tree,
+    /// expandable, nor compound expandable.
+    #[prop_or_default]
+    pub virtualized: bool,
+
+    /// The fixed height of a row in pixels, required for virtualized rendering.
+    ///
+    /// The visible window is evaluated from the scroll position, below the caption and the
+    /// header of the table. The size of the scrolling container is measured again when the
+    /// window gets resized, but not when only the container changes its size.
+    #[prop_or(48)]
+    pub row_height: u32,
+
+    /// The number of additional rows rendered before and after the visible window.
+    #[prop_or(10)]
+    pub buffer_rows: usize,
+}
+
+/// The mode of selecting rows in a [`Table`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum TableSelectionMode {
+    #[default]
+    None,
+    /// Select a single row, using radio buttons.
+    Single,
+    /// Select multiple rows, using checkboxes.
+    Multiple,
 }
 
 #[function_component(Table)]
 pub fn table<C, M>(props: &TableProperties<C, M>) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let class = table_classes(props);
+
+    let drag = use_state(DragState::default);
+    let expansion = use_reducer_eq(Expansion::default);
+    let viewport = use_state_eq(Viewport::default);
+    let scroll_ref = use_node_ref();
+
+    {
+        let viewport = viewport.clone();
+        let scroll_ref = scroll_ref.clone();
+        use_effect_with_deps(
+            move |virtualized| {
+                let measure = move || {
+                    if let Some(element) = scroll_ref.cast::<web_sys::Element>() {
+                        viewport.set(Viewport::from(&element));
+                    }
+                };
+                measure();
+                let listener = virtualized.then(|| {
+                    EventListener::new(&gloo_utils::window(), "resize", move |_| measure())
+                });
+                move || drop(listener)
+            },
+            props.virtualized,
+        );
+    }
+
+    let state = TableState {
+        window: is_virtualized(props).then(|| {
+            virtual_rows(
+                &viewport,
+                props.row_height,
+                props.buffer_rows,
+                props.entries.len(),
+            )
+        }),
+        drag: (*drag).clone(),
+        uncontrolled: (!props.controlled).then(|| Uncontrolled {
+            expansion: Rc::new((*expansion).clone()),
+            dispatch: {
+                let dispatcher = expansion.dispatcher();
+                Callback::from(move |action| dispatcher.dispatch(action))
+            },
+        }),
+    };
+
+    let table_ref = use_node_ref();
+    let onkeydown = focus::use_grid_focus(table_ref.clone(), props.grid.is_some());
+
+    let table = html! (
+        <table
+            ref={table_ref}
+            id={&props.id}
+            {class}
+            role={table_role(props)}
+            {onkeydown}
+        >
+            { render_caption(props) }
+            { render_header(props, &state) }
+            { render_entries(props, &state) }
+            { render_footer(props) }
+        </table>
+    );
+
+    let table = match props.virtualized {
+        true => {
+            let onscroll = {
+                let scroll_ref = scroll_ref.clone();
+                Callback::from(move |_: Event| {
+                    if let Some(element) = scroll_ref.cast::<web_sys::Element>() {
+                        viewport.set(Viewport::from(&element));
+                    }
+                })
+            };
+            html!(
+                <div ref={scroll_ref} style="overflow-y: auto; height: 100%;" {onscroll}>
+                    { table }
+                </div>
+            )
+        }
+        false => table,
+    };
+
+    match props.sticky_columns {
+        0 => table,
+        _ => html!(
+            <div class="pf-c-scroll-outer-wrapper">
+                <div class="pf-c-scroll-inner-wrapper">
+                    { table }
+                </div>
+            </div>
+        ),
+    }
+}
+
+/// Evaluate the classes of the table element.
+fn table_classes<C, M>(props: &TableProperties<C, M>) -> Classes
 where
     C: Clone + Eq + 'static,
     M: PartialEq + TableModel<C> + 'static,
 {
     let mut class = classes!("pf-c-table");
 
+    if props.tree {
+        class.push(classes!("pf-m-tree-view"));
+    }
+
     if props
         .header
         .as_ref()
@@ -69,39 +368,23 @@ where
 
     class.extend_from(&props.grid);
 
-    match props.mode {
-        TableMode::Compact => {
-            class.push(classes!("pf-m-compact"));
-        }
-        TableMode::CompactNoBorders => {
-            class.push(classes!("pf-m-compact", "pf-m-no-border-rows"));
-        }
-        TableMode::CompactExpandable => {
-            class.push(classes!("pf-m-compact"));
-        }
-        TableMode::Expandable => {
-            class.push(classes!("pf-m-expandable"));
-        }
-        TableMode::Default => {}
-    };
+    if props.compact || props.mode.is_compact() {
+        class.push(classes!("pf-m-compact"));
+    }
+
+    if is_expandable(props) {
+        class.push(classes!("pf-m-expandable"));
+    }
 
-    if !props.borders {
+    if !props.borders || !props.mode.has_borders() {
         class.push(classes!("pf-m-no-border-rows"));
     }
 
-    html! (
-        <table
-            id={&props.id}
-            {class}
-            role="grid"
-        >
-            if let Some(caption) = &props.caption {
-                <caption>{caption}</caption>
-            }
-            { render_header(props) }
-            { render_entries(props) }
-        </table>
-    )
+    if props.striped && !is_grouped(props) {
+        class.push(classes!("pf-m-striped"));
+    }
+
+    class
 }
 
 fn is_expandable<C, M>(props: &TableProperties<C, M>) -> bool
@@ -109,210 +392,2132 @@ where
     C: Clone + Eq + 'static,
     M: PartialEq + TableModel<C> + 'static,
 {
-    matches!(
-        props.mode,
-        TableMode::Expandable | TableMode::CompactExpandable
-    )
+    !props.tree && (props.expandable || props.mode.is_expandable())
 }
 
-fn render_header<C, M>(props: &TableProperties<C, M>) -> Html
+/// Rows of a tree can't be reordered, as they are nested.
+fn is_draggable<C, M>(props: &TableProperties<C, M>) -> bool
 where
     C: Clone + Eq + 'static,
     M: PartialEq + TableModel<C> + 'static,
 {
-    let expandable = is_expandable(props);
-    match &props.header {
-        Some(header) => {
-            let mut header = header.clone();
-            let props = Rc::make_mut(&mut header.props);
-            props.expandable = expandable;
-            VNode::VComp(yew::virtual_dom::VComp::from(header))
-        }
-        None => html!(),
+    !props.tree && props.draggable
+}
+
+/// Evaluate the ARIA role of the table, see [`TableProperties::role`].
+fn table_role<C, M>(props: &TableProperties<C, M>) -> AttrValue
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    if let Some(role) = &props.role {
+        return role.clone();
+    }
+
+    if props.tree {
+        return "treegrid".into();
+    }
+
+    let sortable = props.header.as_ref().map_or(false, |header| {
+        header
+            .props
+            .children
+            .iter()
+            .any(|column| column.props.sortable)
+    });
+
+    let interactive = sortable
+        || props.grid.is_some()
+        || is_expandable(props)
+        || is_compound(props)
+        || props.selection_mode != TableSelectionMode::None
+        || props.favorites
+        || is_draggable(props)
+        || props.clickable_rows;
+
+    match interactive {
+        true => "grid".into(),
+        false => "table".into(),
     }
 }
 
-fn render_entries<C, M>(props: &TableProperties<C, M>) -> Html
+/// Check if only the rows within the scroll window get rendered.
+fn is_virtualized<C, M>(props: &TableProperties<C, M>) -> bool
 where
     C: Clone + Eq + 'static,
     M: PartialEq + TableModel<C> + 'static,
 {
-    html!(if is_expandable(props) {
-        { for props.entries.iter().map(|entry| render_expandable_entry(props, entry) )}
-    } else {
-        <tbody role="rowgroup">
-            { for props.entries.iter().map(|entry| render_normal_entry(props, entry) )}
-        </tbody>
-    })
+    props.virtualized && !props.tree && !is_expandable(props) && !is_compound(props)
 }
 
-fn render_normal_entry<C, M>(
-    props: &TableProperties<C, M>,
-    entry: TableModelEntry<M::Item, M::Key>,
-) -> Html
+/// Evaluate the range of rows within the scroll window, extended by the buffer rows.
+fn virtual_rows(viewport: &Viewport, row_height: u32, buffer: usize, total: usize) -> Range<usize> {
+    if row_height == 0 {
+        return 0..total;
+    }
+
+    let row_height = row_height as usize;
+    // the rows start below the caption and the header
+    let first = (viewport.scroll_top - viewport.offset).max(0) as usize / row_height;
+    let visible = (viewport.height.max(0) as usize + row_height - 1) / row_height;
+
+    let start = first.saturating_sub(buffer).min(total);
+    let end = (first + visible + buffer).min(total);
+
+    start..end
+}
+
+/// Split the rows before the scroll window into spacer rows, one for an odd number of rows, two for
+/// an even number.
+///
+/// This keeps the parity of the rendered rows, which get striped by their position.
+fn leading_spacers(rows: usize) -> Vec<usize> {
+    match rows {
+        0 => vec![],
+        rows if rows % 2 == 1 => vec![rows],
+        rows => vec![rows - 1, 1],
+    }
+}
+
+/// Render a spacer row, standing in for the rows outside the scroll window.
+fn render_spacer<C, M>(props: &TableProperties<C, M>, rows: usize) -> Html
 where
     C: Clone + Eq + 'static,
     M: PartialEq + TableModel<C> + 'static,
 {
+    if rows == 0 {
+        return html!();
+    }
+
+    let style = format!("height: {}px;", rows * props.row_height as usize);
     html!(
-        <tr role="row" key={entry.key}>
-            { render_row(props, entry.value)}
+        <tr aria-hidden="true" {style}>
+            <td colspan={total_columns(props).to_string()} style="padding: 0; border: 0;" />
         </tr>
     )
 }
 
-fn render_expandable_entry<C, M>(
-    props: &TableProperties<C, M>,
-    entry: TableModelEntry<M::Item, M::Key>,
-) -> Html
+/// Check if any column has compound expandable cells.
+fn is_compound<C, M>(props: &TableProperties<C, M>) -> bool
 where
     C: Clone + Eq + 'static,
     M: PartialEq + TableModel<C> + 'static,
 {
-    let expanded = entry.expanded;
-    let key = entry.key;
+    props.header.as_ref().map_or(false, |header| {
+        header
+            .props
+            .children
+            .iter()
+            .any(|column| column.props.compound)
+    })
+}
 
-    /*
-    let onclick = match expanded {
-        true => ctx.link().callback(move |_: MouseEvent| Msg::Collapse(idx)),
-        false => ctx.link().callback(move |_: MouseEvent| Msg::Expand(idx)),
-    };
-     */
+/// Check if entries are rendered as groups of rows, each in their own `tbody`.
+fn is_grouped<C, M>(props: &TableProperties<C, M>) -> bool
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    is_expandable(props) || is_compound(props)
+}
 
-    let mut classes = classes!("pf-c-button");
-    classes.push(classes!("pf-m-plain"));
-    if expanded {
-        classes.push(classes!("pf-m-expanded"));
+/// The stripe of a group of rows.
+fn group_stripe<C, M>(props: &TableProperties<C, M>, index: usize) -> Classes
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    match props.striped && index % 2 == 0 {
+        true => classes!("pf-m-striped"),
+        false => Classes::new(),
     }
+}
 
-    let aria_expanded = match expanded {
-        true => "true",
-        false => "false",
-    };
-
-    let mut expanded_class = Classes::new();
-    if expanded {
-        expanded_class.push(classes!("pf-m-expanded"));
+/// The state of the sticky data columns.
+fn sticky_data_columns<C, M>(props: &TableProperties<C, M>) -> Vec<StickyColumn>
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    if props.sticky_columns == 0 {
+        return vec![];
     }
 
-    let mut cols = props
+    let columns: Vec<_> = props
         .header
-        .as_ref()
-        .map_or(0, |header| header.props.children.len())
-        + 1;
+        .iter()
+        .flat_map(|header| header.props.children.iter())
+        .map(|column| column.props)
+        .collect();
 
-    let mut cells: Vec<Html> = Vec::with_capacity(cols);
+    sticky_columns(
+        columns.iter().map(Rc::as_ref),
+        props.sticky_columns,
+        control_columns(props),
+    )
+}
 
-    if !entry
-        .value
-        .is_full_width_details()
-        .unwrap_or(props.full_width_details)
-    {
-        cells.push(html! {<td></td>});
-        cols -= 1;
+/// The number of columns in front of the data columns (toggle, selection, ...).
+fn control_columns<C, M>(props: &TableProperties<C, M>) -> usize
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let mut columns = 0;
+    if is_draggable(props) {
+        columns += 1;
     }
-
-    for cell in entry.value.render_details() {
-        let mut classes = Classes::new();
-        classes.extend_from(&cell.modifiers);
-
-        cells.push(html! {
-            <td class={classes} colspan={cell.cols.to_string()}>
-                <div class="pf-c-table__expandable-row-content">
-                    { cell.content }
-                </div>
-            </td>
-        });
-
-        if cols > cell.cols {
-            cols -= cell.cols;
-        } else {
-            cols = 0;
-        }
-        if cols == 0 {
-            break;
-        }
+    if is_expandable(props) {
+        columns += 1;
     }
-
-    if cols > 0 {
-        cells.push(html! {
-            <td colspan={cols.to_string()}></td>
-        });
+    if props.selection_mode != TableSelectionMode::None {
+        columns += 1;
     }
-
-    let mut tr_classes = classes!("pf-c-table__expandable-row");
-    tr_classes.extend(expanded_class.clone());
-
-    let onclick = props.onexpand.reform(move |_| (key.clone(), !expanded));
-
-    html! (
-        <tbody role="rowgroup" class={expanded_class}>
-            <tr>
-                <td class="pf-c-table__toggle">
-                    <button class={classes} {onclick} aria-expanded={aria_expanded}>
-                        <div class="pf-c-table__toggle-icon">
-                            { Icon::AngleDown }
-                        </div>
-                    </button>
-                </td>
-
-                { render_row(props, entry.value) }
-            </tr>
-
-            <tr class={tr_classes}>
-                { cells }
-            </tr>
-        </tbody>
-    )
+    if props.favorites {
+        columns += 1;
+    }
+    columns
 }
 
-fn render_row<C, M>(props: &TableProperties<C, M>, entry: &M::Item) -> Vec<Html>
+/// The number of data columns, as defined by the header.
+fn data_columns<C, M>(props: &TableProperties<C, M>) -> usize
 where
     C: Clone + Eq + 'static,
     M: PartialEq + TableModel<C> + 'static,
 {
-    let len = props
+    props
         .header
         .as_ref()
-        .map_or(0, |header| header.props.children.len());
-
-    let mut cells: Vec<Html> = Vec::with_capacity(len);
+        .map_or(0, |header| header.props.children.len())
+}
 
-    for column in props
-        .header
-        .iter()
-        .flat_map(|header| header.props.children.iter())
-    {
-        let cell = entry.render_cell(&CellContext {
+/// The number of all columns, including control and action columns.
+fn total_columns<C, M>(props: &TableProperties<C, M>) -> usize
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let actions = match &props.header {
+        Some(header) if !header.props.hide_actions => 1,
+        _ => 0,
+    };
+    control_columns(props) + data_columns(props) + actions
+}
+
+fn render_header<C, M>(props: &TableProperties<C, M>, state: &TableState) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    match header(props, state) {
+        Some(header) => VNode::VComp(yew::virtual_dom::VComp::from(header)),
+        None => html!(),
+    }
+}
+
+/// The header, with the state of the table injected.
+fn header<C, M>(props: &TableProperties<C, M>, state: &TableState) -> Option<VChild<TableHeader<C>>>
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let expandable = is_expandable(props);
+    props.header.as_ref().map(|header| {
+        let mut header = header.clone();
+        let header_props = Rc::make_mut(&mut header.props);
+        header_props.expandable = expandable;
+        header_props.tree = props.tree;
+        header_props.draggable = is_draggable(props);
+        header_props.oncolumnreorder = props.oncolumnreorder.clone();
+        header_props.selection = props.selection_mode;
+        header_props.favorites = props.favorites;
+        header_props.all_selected =
+            !props.entries.is_empty() && props.entries.iter().all(|entry| entry.selected);
+        header_props.onselectall = props.onselectall.clone();
+        header_props.all_expanded = !props.entries.is_empty()
+            && props
+                .entries
+                .iter()
+                .all(|entry| state.is_expanded(&entry.key, entry.expanded));
+        header_props.onexpandall = state.onexpandall(
+            props.entries.iter().map(|entry| entry.key).collect(),
+            &props.onexpandall,
+        );
+        header_props.sticky_columns = sticky_data_columns(props);
+        header
+    })
+}
+
+fn render_footer<C, M>(props: &TableProperties<C, M>) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    match footer(props) {
+        Some(footer) => VNode::VComp(yew::virtual_dom::VComp::from(footer)),
+        None => html!(),
+    }
+}
+
+/// The footer, aligned to the columns of the table.
+fn footer<C, M>(props: &TableProperties<C, M>) -> Option<VChild<TableFooter>>
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let mut footer = props.footer.clone()?;
+    let footer_props = Rc::make_mut(&mut footer.props);
+    footer_props.control_columns = control_columns(props);
+    footer_props.data_columns = data_columns(props);
+    footer_props.actions =
+        total_columns(props) > footer_props.control_columns + footer_props.data_columns;
+    Some(footer)
+}
+
+/// The classes of a row, and its click handler.
+fn row_click<C, M>(
+    props: &TableProperties<C, M>,
+    key: &M::Key,
+) -> (Classes, Option<Callback<MouseEvent>>)
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let active = props.active.as_ref() == Some(key);
+
+    if !props.clickable_rows {
+        return (classes!(active.then_some("pf-m-selected")), None);
+    }
+
+    let key = key.clone();
+    (
+        classes!("pf-m-hoverable", active.then_some("pf-m-selected")),
+        Some(props.onrowclick.reform(move |_: MouseEvent| key.clone())),
+    )
+}
+
+/// Prevent clicks on controls from being reported as a row click.
+fn stop_propagation() -> Callback<MouseEvent> {
+    Callback::from(|event: MouseEvent| event.stop_propagation())
+}
+
+fn render_select<C, M>(props: &TableProperties<C, M>, key: &M::Key, selected: bool) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let input_type = match props.selection_mode {
+        TableSelectionMode::None => return html!(),
+        TableSelectionMode::Single => "radio",
+        TableSelectionMode::Multiple => "checkbox",
+    };
+
+    let onchange = {
+        let key = key.clone();
+        props
+            .onselect
+            .reform(move |_: Event| (key.clone(), !selected))
+    };
+
+    html!(
+        <td class="pf-c-table__check" onclick={stop_propagation()}>
+            <input
+                type={input_type}
+                aria-label="Select row"
+                checked={selected}
+                {onchange}
+            />
+        </td>
+    )
+}
+
+fn render_favorite<C, M>(props: &TableProperties<C, M>, key: &M::Key, favorite: bool) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    if !props.favorites {
+        return html!();
+    }
+
+    let onclick = {
+        let key = key.clone();
+        props.onfavorite.reform(move |event: MouseEvent| {
+            event.stop_propagation();
+            (key.clone(), !favorite)
+        })
+    };
+
+    let mut class = classes!("pf-c-table__favorite");
+    if favorite {
+        class.push(classes!("pf-m-favorited"));
+    }
+
+    html!(
+        <td {class}>
+            <button
+                class="pf-c-button pf-m-plain"
+                type="button"
+                aria-label={match favorite { true => "Starred", false => "Not starred" }}
+                {onclick}
+            >
+                <i class="fas fa-star" aria-hidden="true"></i>
+            </button>
+        </td>
+    )
+}
+
+fn row_drag<C, M>(props: &TableProperties<C, M>, drag: &DragState, index: usize) -> DragHandlers
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    match is_draggable(props) {
+        true => DragHandlers::new(drag, index, &props.onreorder),
+        false => DragHandlers::default(),
+    }
+}
+
+fn render_drag_handle<C, M>(props: &TableProperties<C, M>) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    if !is_draggable(props) {
+        return html!();
+    }
+
+    html!(
+        <td class="pf-c-table__draggable">
+            <button class="pf-c-button pf-m-plain" type="button" aria-label="Drag row">
+                { Icon::GripVertical }
+            </button>
+        </td>
+    )
+}
+
+fn render_entries<C, M>(props: &TableProperties<C, M>, state: &TableState) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    if props.loading {
+        return render_skeleton(props);
+    }
+
+    if props.entries.is_empty() {
+        if let Some(empty) = &props.empty {
+            return html!(
+                <tbody role="rowgroup">
+                    <tr role="row">
+                        <td colspan={total_columns(props).to_string()}>
+                            { empty.clone() }
+                        </td>
+                    </tr>
+                </tbody>
+            );
+        }
+    }
+
+    let expanded: Rc<[M::Key]> = match props.single_expand {
+        true => props
+            .entries
+            .iter()
+            .filter(|entry| state.is_expanded(&entry.key, entry.expanded))
+            .map(|entry| entry.key)
+            .collect(),
+        false => Rc::new([]),
+    };
+
+    html!(if props.tree {
+        <tbody role="rowgroup">
+            { render_tree(props, props.entries.iter(), 1, state) }
+        </tbody>
+    } else if is_expandable(props) {
+        { for props.entries.iter().enumerate().map(|(index, entry)| render_expandable_entry(props, index, entry, &expanded, state) )}
+    } else if is_compound(props) {
+        { for props.entries.iter().enumerate().map(|(index, entry)| render_compound_entry(props, index, entry, state) )}
+    } else if let Some(window) = &state.window {
+        <tbody role="rowgroup">
+            { for leading_spacers(window.start).into_iter().map(|rows| render_spacer(props, rows)) }
+            { for props.entries.iter().enumerate().skip(window.start).take(window.len()).map(|(index, entry)| render_normal_entry(props, index, entry, state) )}
+            { render_spacer(props, props.entries.len() - window.end) }
+        </tbody>
+    } else {
+        <tbody role="rowgroup">
+            { for props.entries.iter().enumerate().map(|(index, entry)| render_normal_entry(props, index, entry, state) )}
+        </tbody>
+    })
+}
+
+/// Render a level of a tree, recursing into the children of expanded entries.
+fn render_tree<'i, C, M>(
+    props: &'i TableProperties<C, M>,
+    entries: M::Iterator<'i>,
+    level: usize,
+    state: &TableState,
+) -> Vec<Html>
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let entries: Vec<_> = entries.collect();
+    let size = entries.len();
+
+    let mut rows = Vec::with_capacity(size);
+    for (index, entry) in entries.into_iter().enumerate() {
+        let expanded = state.is_expanded(&entry.key, entry.expanded);
+        let children = props.entries.children(&entry.key);
+        let position = TreePosition {
+            level,
+            size,
+            position: index + 1,
+        };
+
+        rows.push(render_tree_entry(
+            props,
+            entry,
+            position,
+            children.is_some(),
+            state,
+        ));
+
+        if expanded {
+            if let Some(children) = children {
+                rows.extend(render_tree(props, children, level + 1, state));
+            }
+        }
+    }
+
+    rows
+}
+
+/// The position of an entry in a tree.
+struct TreePosition {
+    level: usize,
+    size: usize,
+    position: usize,
+}
+
+fn render_tree_entry<C, M>(
+    props: &TableProperties<C, M>,
+    entry: TableModelEntry<M::Item, M::Key>,
+    position: TreePosition,
+    has_children: bool,
+    state: &TableState,
+) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let key = entry.key;
+    let expanded = state.is_expanded(&key, entry.expanded);
+
+    let (mut class, onclick) = row_click(props, &key);
+    class.extend(entry.row_class);
+    if expanded {
+        class.push(classes!("pf-m-expanded"));
+    }
+
+    let mut cells: Vec<Html> = Vec::with_capacity(data_columns(props) + 1);
+    let sticky = sticky_data_columns(props);
+
+    for (i, column) in props
+        .header
+        .iter()
+        .flat_map(|header| header.props.children.iter())
+        .enumerate()
+    {
+        let mut cell = entry.value.render_cell(&CellContext {
             column: &column.props.index,
         });
-        let mut class = match cell.center {
-            true => classes!("pf-m-center"),
-            false => Classes::new(),
+
+        let class = match i {
+            0 => {
+                cell.content = render_tree_main(
+                    &state.onexpand(&props.onexpand),
+                    cell.content,
+                    &key,
+                    expanded,
+                    has_children,
+                );
+                classes!("pf-c-table__tree-view-title-cell")
+            }
+            _ => Classes::new(),
         };
-        class.extend_from(&cell.text_modifier);
 
-        let label = column.props.label.clone();
-        cells.push(html!(
-            <td {class} data-label={label.unwrap_or_default()}>
-                {cell.content}
-            </td>
-        ));
+        cells.push(render_cell(&column.props, sticky.get(i), cell, class));
     }
 
-    let actions = entry.actions();
-    if !actions.is_empty() {
-        cells.push(html!(
-            <td class="pf-c-table__action">
-                <Dropdown
-                    plain=true
-                    toggle={html!(<KebabToggle/>)}
-                >
-                    { actions }
-                </Dropdown>
+    cells.extend(render_actions(entry.value));
+
+    html!(
+        <tr
+            role="row"
+            {class}
+            {onclick}
+            key={key.clone()}
+            aria-level={position.level.to_string()}
+            aria-setsize={position.size.to_string()}
+            aria-posinset={position.position.to_string()}
+            aria-expanded={has_children.then(|| expanded.to_string())}
+        >
+            { render_select(props, &key, entry.selected) }
+            { render_favorite(props, &key, entry.favorite) }
+            { cells }
+        </tr>
+    )
+}
+
+/// Render the content of the first cell of a tree entry, including the toggle.
+fn render_tree_main<K>(
+    onexpand: &Callback<(K, bool)>,
+    content: Html,
+    key: &K,
+    expanded: bool,
+    has_children: bool,
+) -> Html
+where
+    K: Clone + 'static,
+{
+    let onclick = {
+        let key = key.clone();
+        onexpand.reform(move |event: MouseEvent| {
+            event.stop_propagation();
+            (key.clone(), !expanded)
+        })
+    };
+
+    let mut class = classes!("pf-c-button", "pf-m-plain");
+    if expanded {
+        class.push(classes!("pf-m-expanded"));
+    }
+
+    html!(
+        <div class="pf-c-table__tree-view-main">
+            if has_children {
+                <span class="pf-c-table__toggle">
+                    <button
+                        type="button"
+                        {class}
+                        aria-label="Details"
+                        aria-expanded={expanded.to_string()}
+                        {onclick}
+                    >
+                        { render_toggle_icon(expanded) }
+                    </button>
+                </span>
+            }
+            <div class="pf-c-table__tree-view-text">
+                <span class="pf-c-table__text">{ content }</span>
+            </div>
+        </div>
+    )
+}
+
+fn render_skeleton<C, M>(props: &TableProperties<C, M>) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let controls = control_columns(props);
+    let data = data_columns(props);
+    let trailing = total_columns(props) - controls - data;
+
+    html!(
+        <tbody role="rowgroup" aria-busy="true">
+            { for (0..props.skeleton_rows).map(|_| html!(
+                <tr role="row">
+                    { for (0..controls).map(|_| html!(<td></td>)) }
+                    { for (0..data).map(|_| html!(
+                        <td>
+                            <div class="pf-c-skeleton">
+                                <span class="pf-screen-reader">{ "Loading…" }</span>
+                            </div>
+                        </td>
+                    )) }
+                    { for (0..trailing).map(|_| html!(<td></td>)) }
+                </tr>
+            )) }
+        </tbody>
+    )
+}
+
+fn render_normal_entry<C, M>(
+    props: &TableProperties<C, M>,
+    index: usize,
+    entry: TableModelEntry<M::Item, M::Key>,
+    state: &TableState,
+) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let (mut class, onclick) = row_click(props, &entry.key);
+    class.extend(entry.row_class);
+    let drag = row_drag(props, &state.drag, index);
+
+    html!(
+        <tr role="row" {class} {onclick} key={entry.key.clone()} ondragstart={drag.ondragstart} ondragover={drag.ondragover} ondrop={drag.ondrop} ondragend={drag.ondragend} draggable={drag.draggable}>
+            { render_drag_handle(props) }
+            { render_select(props, &entry.key, entry.selected) }
+            { render_favorite(props, &entry.key, entry.favorite) }
+            { render_row(props, entry.value)}
+        </tr>
+    )
+}
+
+fn render_expandable_entry<C, M>(
+    props: &TableProperties<C, M>,
+    index: usize,
+    entry: TableModelEntry<M::Item, M::Key>,
+    others: &Rc<[M::Key]>,
+    state: &TableState,
+) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let expanded = state.is_expanded(&entry.key, entry.expanded);
+    let key = entry.key;
+
+    /*
+    let onclick = match expanded {
+        true => ctx.link().callback(move |_: MouseEvent| Msg::Collapse(idx)),
+        false => ctx.link().callback(move |_: MouseEvent| Msg::Expand(idx)),
+    };
+     */
+
+    let mut classes = classes!("pf-c-button");
+    classes.push(classes!("pf-m-plain"));
+    if expanded {
+        classes.push(classes!("pf-m-expanded"));
+    }
+
+    let aria_expanded = match expanded {
+        true => "true",
+        false => "false",
+    };
+
+    let mut expanded_class = Classes::new();
+    if expanded {
+        expanded_class.push(classes!("pf-m-expanded"));
+    }
+
+    let controls = control_columns(props);
+    let mut cols = data_columns(props) + controls;
+
+    let mut cells: Vec<Html> = Vec::with_capacity(cols);
+
+    if !entry
+        .value
+        .is_full_width_details()
+        .unwrap_or(props.full_width_details)
+    {
+        // skip the control columns
+        for _ in 0..controls {
+            cells.push(html! {<td></td>});
+        }
+        cols -= controls;
+    }
+
+    // only render the details when they are shown, the empty row keeps the layout
+    let details = match expanded {
+        true => entry.value.render_details(),
+        false => vec![],
+    };
+    let (spans, cols) = clamp_spans(details.iter().map(|cell| cell.cols), cols);
+
+    for (cell, span) in details.into_iter().zip(spans) {
+        let mut classes = Classes::new();
+        classes.extend_from(&cell.modifiers);
+
+        cells.push(html! {
+            <td class={classes} colspan={span.to_string()}>
+                <div class="pf-c-table__expandable-row-content">
+                    { cell.content }
+                </div>
             </td>
-        ));
+        });
     }
 
+    if cols > 0 {
+        cells.push(html! {
+            <td colspan={cols.to_string()}></td>
+        });
+    }
+
+    let stripe = group_stripe(props, index);
+
+    let mut tr_classes = classes!("pf-c-table__expandable-row");
+    tr_classes.extend(expanded_class.clone());
+    tr_classes.extend(stripe.clone());
+
+    let onclick = {
+        let key = key.clone();
+        let others = others.clone();
+        let onexpand = state.onexpand(&props.onexpand);
+        Callback::from(move |event: MouseEvent| {
+            event.stop_propagation();
+            if !expanded {
+                // collapse others first, in single expand mode
+                for other in others.iter().filter(|other| **other != key) {
+                    onexpand.emit((other.clone(), false));
+                }
+            }
+            onexpand.emit((key.clone(), !expanded));
+        })
+    };
+
+    let (mut row_class, row_onclick) = row_click(props, &key);
+    row_class.extend(entry.row_class);
+    row_class.extend(stripe);
+    let drag = row_drag(props, &state.drag, index);
+
+    html! (
+        <tbody role="rowgroup" class={expanded_class}>
+            <tr class={row_class} onclick={row_onclick} ondragstart={drag.ondragstart} ondragover={drag.ondragover} ondrop={drag.ondrop} ondragend={drag.ondragend} draggable={drag.draggable}>
+                { render_drag_handle(props) }
+                <td class="pf-c-table__toggle">
+                    <button class={classes} {onclick} aria-expanded={aria_expanded}>
+                        { render_toggle_icon(expanded) }
+                    </button>
+                </td>
+
+                { render_select(props, &key, entry.selected) }
+                { render_favorite(props, &key, entry.favorite) }
+
+                { render_row(props, entry.value) }
+            </tr>
+
+            <tr class={tr_classes}>
+                { cells }
+            </tr>
+        </tbody>
+    )
+}
+
+fn render_caption<C, M>(props: &TableProperties<C, M>) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    html!(
+        if let Some(caption) = &props.caption.0 {
+            <caption>{ caption.clone() }</caption>
+        }
+    )
+}
+
+/// Clamp the requested spans of the details cells to the available columns.
+///
+/// Every cell gets at least one column, so that no content is dropped. Returns the spans, and the
+/// number of columns left unused.
+fn clamp_spans(
+    requested: impl ExactSizeIterator<Item = usize>,
+    cols: usize,
+) -> (Vec<usize>, usize) {
+    let len = requested.len();
+    let mut remaining = cols;
+
+    let spans = requested
+        .enumerate()
+        .map(|(i, requested)| {
+            // keep a column for each of the following cells
+            let available = remaining.saturating_sub(len - i - 1);
+            let span = requested.min(available).max(1);
+            remaining = remaining.saturating_sub(span);
+            span
+        })
+        .collect();
+
+    (spans, remaining)
+}
+
+fn render_compound_entry<C, M>(
+    props: &TableProperties<C, M>,
+    index: usize,
+    entry: TableModelEntry<M::Item, M::Key>,
+    state: &TableState,
+) -> Html
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let key = entry.key;
+    let expanded = props.entries.compound_expanded(&key);
+
+    let mut details = None;
+    let mut cells: Vec<Html> = Vec::with_capacity(data_columns(props) + 1);
+    let sticky = sticky_data_columns(props);
+
+    for (i, column) in props
+        .header
+        .iter()
+        .flat_map(|header| header.props.children.iter())
+        .enumerate()
+    {
+        let index = &column.props.index;
+        let context = CellContext { column: index };
+
+        let cell = match column.props.compound {
+            true => entry.value.render_compound_cell(&context),
+            false => entry.value.render_cell(&context).into(),
+        };
+
+        match cell {
+            CompoundCell::Cell(cell) => cells.push(render_cell(
+                &column.props,
+                sticky.get(i),
+                cell,
+                Classes::new(),
+            )),
+            CompoundCell::Toggle {
+                mut cell,
+                details: content,
+            } => {
+                let active = expanded.as_ref() == Some(index);
+
+                let mut class = classes!("pf-c-table__compound-expansion-toggle");
+                if active {
+                    class.push(classes!("pf-m-expanded"));
+                    details = Some(content);
+                }
+
+                let onclick = {
+                    let key = key.clone();
+                    let index = index.clone();
+                    props.oncompoundexpand.reform(move |event: MouseEvent| {
+                        event.stop_propagation();
+                        (key.clone(), (!active).then(|| index.clone()))
+                    })
+                };
+
+                cell.content = html!(
+                    <button class="pf-c-table__button" {onclick} aria-expanded={active.to_string()}>
+                        { cell.content }
+                    </button>
+                );
+
+                cells.push(render_cell(&column.props, sticky.get(i), cell, class));
+            }
+        }
+    }
+
+    cells.extend(render_actions(entry.value));
+
+    let class = match details.is_some() {
+        true => classes!("pf-m-expanded"),
+        false => Classes::new(),
+    };
+
+    let stripe = group_stripe(props, index);
+    let (mut row_class, row_onclick) = row_click(props, &key);
+    row_class.extend(entry.row_class);
+    row_class.extend(stripe.clone());
+    let drag = row_drag(props, &state.drag, index);
+
+    html!(
+        <tbody role="rowgroup" {class} key={key.clone()}>
+            <tr role="row" class={row_class} onclick={row_onclick} ondragstart={drag.ondragstart} ondragover={drag.ondragover} ondrop={drag.ondrop} ondragend={drag.ondragend} draggable={drag.draggable}>
+                { render_drag_handle(props) }
+                { render_select(props, &key, entry.selected) }
+                { render_favorite(props, &key, entry.favorite) }
+                { cells }
+            </tr>
+            if let Some(details) = details {
+                <tr class={classes!("pf-c-table__expandable-row", "pf-m-expanded", stripe)} role="row">
+                    <td colspan={total_columns(props).to_string()}>
+                        <div class="pf-c-table__expandable-row-content">
+                            { details }
+                        </div>
+                    </td>
+                </tr>
+            }
+        </tbody>
+    )
+}
+
+fn render_row<C, M>(props: &TableProperties<C, M>, entry: &M::Item) -> Vec<Html>
+where
+    C: Clone + Eq + 'static,
+    M: PartialEq + TableModel<C> + 'static,
+{
+    let mut cells: Vec<Html> = Vec::with_capacity(data_columns(props) + 1);
+    let sticky = sticky_data_columns(props);
+
+    for (i, column) in props
+        .header
+        .iter()
+        .flat_map(|header| header.props.children.iter())
+        .enumerate()
+    {
+        let cell = entry.render_cell(&CellContext {
+            column: &column.props.index,
+        });
+        cells.push(render_cell(
+            &column.props,
+            sticky.get(i),
+            cell,
+            Classes::new(),
+        ));
+    }
+
+    cells.extend(render_actions(entry));
+
     cells
 }
+
+fn render_cell<C>(
+    column: &TableColumnProperties<C>,
+    sticky: Option<&StickyColumn>,
+    cell: Cell,
+    mut class: Classes,
+) -> Html
+where
+    C: Clone + Eq + 'static,
+{
+    if cell.center {
+        class.push(classes!("pf-m-center"));
+    } else {
+        class.extend_from(&column.text_align);
+    }
+    class.extend_from(&cell.text_modifier);
+    class.extend_from(&column.visibility);
+    class.extend(cell.class);
+
+    let style = sticky.and_then(|sticky| sticky.apply(&mut class));
+
+    let label = column
+        .data_label
+        .as_ref()
+        .or(column.label.as_ref())
+        .cloned();
+    html!(
+        <td {class} {style} data-label={label}>
+            {cell.content}
+        </td>
+    )
+}
+
+fn render_actions<C, T>(entry: &T) -> Option<Html>
+where
+    C: Clone + Eq + 'static,
+    T: TableEntryRenderer<C>,
+{
+    let actions = entry.actions();
+    if actions.is_empty() {
+        return None;
+    }
+
+    let TableEntryActions { inline, overflow } = actions;
+
+    let overflow = match overflow.is_empty() {
+        true => html!(),
+        false => html!(
+            <Dropdown
+                plain=true
+                toggle={html!(<KebabToggle/>)}
+            >
+                { overflow }
+            </Dropdown>
+        ),
+    };
+
+    if inline.is_empty() {
+        return Some(html!(
+            <td class="pf-c-table__action" onclick={stop_propagation()}>
+                { overflow }
+            </td>
+        ));
+    }
+
+    Some(html!(
+        <td class="pf-c-table__action" onclick={stop_propagation()}>
+            <div class="pf-c-overflow-menu">
+                <div class="pf-c-overflow-menu__content">
+                    <div class="pf-c-overflow-menu__group pf-m-button-group">
+                        { for inline.into_iter().map(|action| html!(
+                            <div class="pf-c-overflow-menu__item">{ action }</div>
+                        )) }
+                    </div>
+                </div>
+                <div class="pf-c-overflow-menu__control">
+                    { overflow }
+                </div>
+            </div>
+        </td>
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::{Span, Visibility, WithBreakpointExt};
+    use yew::virtual_dom::VTag;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Column {
+        First,
+        Second,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Item(usize);
+
+    impl TableEntryRenderer<Column> for Item {
+        fn render_cell(&self, context: &CellContext<'_, Column>) -> Cell {
+            match context.column {
+                Column::First => html!(self.0),
+                Column::Second => html!("second"),
+            }
+            .into()
+        }
+
+        fn render_compound_cell(&self, context: &CellContext<'_, Column>) -> CompoundCell {
+            match context.column {
+                Column::Second => CompoundCell::Toggle {
+                    cell: html!("second").into(),
+                    details: html!("compound"),
+                },
+                _ => self.render_cell(context).into(),
+            }
+        }
+
+        fn render_details(&self) -> Vec<Span> {
+            DETAILS_RENDERED.with(|rendered| rendered.set(rendered.get() + 1));
+            vec![Span::one(html!("details"))]
+        }
+    }
+
+    thread_local! {
+        /// The number of times details were rendered, by the current test.
+        static DETAILS_RENDERED: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    #[derive(Debug, Default, PartialEq)]
+    struct MockModel {
+        entries: Vec<Item>,
+        selected: bool,
+        compound: Option<Column>,
+        /// Children of the first entry
+        children: Vec<Item>,
+        /// The key of an entry highlighted as dangerous
+        danger: Option<usize>,
+        /// Collapse all entries
+        collapsed: bool,
+    }
+
+    impl MockModel {
+        fn new(len: usize) -> Self {
+            Self {
+                entries: (0..len).map(Item).collect(),
+                ..Default::default()
+            }
+        }
+    }
+
+    impl TableModel<Column> for MockModel {
+        type Iterator<'i> = Box<dyn Iterator<Item = TableModelEntry<'i, Item, usize>> + 'i>;
+        type Item = Item;
+        type Key = usize;
+
+        fn len(&self) -> usize {
+            self.entries.len()
+        }
+
+        fn iter(&self) -> Self::Iterator<'_> {
+            self.iter_items(&self.entries, 0)
+        }
+
+        fn children(&self, key: &usize) -> Option<Self::Iterator<'_>> {
+            (*key == 0 && !self.children.is_empty()).then(|| self.iter_items(&self.children, 100))
+        }
+
+        fn compound_expanded(&self, _key: &usize) -> Option<Column> {
+            self.compound
+        }
+    }
+
+    impl MockModel {
+        fn iter_items<'i>(
+            &'i self,
+            items: &'i [Item],
+            offset: usize,
+        ) -> <Self as TableModel<Column>>::Iterator<'i> {
+            let selected = self.selected;
+            let danger = self.danger;
+            let expanded = !self.collapsed;
+            Box::new(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, value)| TableModelEntry {
+                        value,
+                        key: offset + index,
+                        expanded,
+                        selected,
+                        favorite: false,
+                        row_class: match danger == Some(offset + index) {
+                            true => classes!("pf-m-danger"),
+                            false => Classes::new(),
+                        },
+                    }),
+            )
+        }
+    }
+
+    fn header() -> VChild<TableHeader<Column>> {
+        html_nested!(
+            <TableHeader<Column>>
+                <TableColumn<Column> index={Column::First} label="First" />
+                <TableColumn<Column> index={Column::Second} label="Second" />
+            </TableHeader<Column>>
+        )
+    }
+
+    fn tag(node: &Html) -> &VTag {
+        match node {
+            VNode::VTag(tag) => tag,
+            _ => panic!("Not a tag: {node:?}"),
+        }
+    }
+
+    /// Collect all direct child tags, flattening nested lists.
+    fn children(tag: &VTag) -> Vec<&VTag> {
+        fn collect<'n>(node: &'n Html, result: &mut Vec<&'n VTag>) {
+            match node {
+                VNode::VTag(tag) => result.push(tag),
+                VNode::VList(list) => list.iter().for_each(|node| collect(node, result)),
+                _ => {}
+            }
+        }
+
+        let mut result = vec![];
+        tag.children()
+            .iter()
+            .for_each(|node| collect(node, &mut result));
+        result
+    }
+
+    fn child_tags(node: &Html) -> Vec<&VTag> {
+        children(tag(node))
+    }
+
+    fn attr<'t>(tag: &'t VTag, name: &str) -> Option<&'t str> {
+        tag.attributes
+            .iter()
+            .find_map(|(key, value)| (key == name).then_some(value))
+    }
+
+    /// The number of columns a row spans.
+    fn span(row: &VTag) -> usize {
+        children(row)
+            .into_iter()
+            .map(|cell| attr(cell, "colspan").map_or(1, |cols| cols.parse().unwrap()))
+            .sum()
+    }
+
+    fn render_first(props: &TableProperties<Column, MockModel>) -> Html {
+        let entry = props.entries.iter().next().unwrap();
+        render_expandable_entry(props, 0, entry, &Rc::from([]), &TableState::default())
+    }
+
+    #[test]
+    fn test_selection_details_aligned() {
+        for selection_mode in [
+            TableSelectionMode::None,
+            TableSelectionMode::Single,
+            TableSelectionMode::Multiple,
+        ] {
+            let props = yew::props!(TableProperties<Column, MockModel> {
+                header: header(),
+                entries: MockModel::new(1),
+                mode: TableMode::Expandable,
+                selection_mode,
+            });
+
+            let body = render_first(&props);
+            let rows = child_tags(&body);
+            assert_eq!(rows.len(), 2);
+
+            let expected = match selection_mode {
+                TableSelectionMode::None => 3,
+                _ => 4,
+            };
+            assert_eq!(span(rows[0]), expected, "Main row: {selection_mode:?}");
+            assert_eq!(span(rows[1]), expected, "Details row: {selection_mode:?}");
+        }
+    }
+
+    #[test]
+    fn test_lazy_details() {
+        let props = |collapsed| {
+            yew::props!(TableProperties<Column, MockModel> {
+                header: header(),
+                entries: MockModel {
+                    collapsed,
+                    ..MockModel::new(1)
+                },
+                mode: TableMode::Expandable,
+                selection_mode: TableSelectionMode::Multiple,
+            })
+        };
+
+        DETAILS_RENDERED.with(|rendered| rendered.set(0));
+
+        let body = render_first(&props(true));
+        assert_eq!(DETAILS_RENDERED.with(|rendered| rendered.get()), 0);
+        // the collapsed details row still spans all columns
+        let rows = child_tags(&body);
+        assert_eq!(span(rows[0]), 4);
+        assert_eq!(span(rows[1]), 4);
+
+        render_first(&props(false));
+        assert_eq!(DETAILS_RENDERED.with(|rendered| rendered.get()), 1);
+    }
+
+    #[test]
+    fn test_favorites_details_aligned() {
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(1),
+            mode: TableMode::Expandable,
+            selection_mode: TableSelectionMode::Multiple,
+            favorites: true,
+        });
+
+        let body = render_first(&props);
+        let rows = child_tags(&body);
+        assert_eq!(rows.len(), 2);
+
+        // toggle, select, favorite, two data columns
+        assert_eq!(span(rows[0]), 5);
+        assert_eq!(span(rows[1]), 5);
+        assert_eq!(
+            attr(children(rows[0])[2], "class"),
+            Some("pf-c-table__favorite")
+        );
+        // details start after the control columns
+        assert_eq!(children(rows[1]).len(), 5);
+    }
+
+    struct Actions {
+        inline: bool,
+        overflow: bool,
+    }
+
+    impl TableEntryRenderer<Column> for Actions {
+        fn render_cell(&self, _: &CellContext<'_, Column>) -> Cell {
+            html!().into()
+        }
+
+        fn actions(&self) -> TableEntryActions {
+            let mut actions = TableEntryActions::new();
+            if self.inline {
+                actions = actions.inline(html!(<button>{ "Edit" }</button>));
+            }
+            if self.overflow {
+                actions = actions.overflow(html_nested!(
+                    <crate::prelude::DropdownItem>{ "Delete" }</crate::prelude::DropdownItem>
+                ));
+            }
+            actions
+        }
+    }
+
+    #[test]
+    fn test_actions() {
+        let render = |inline, overflow| render_actions(&Actions { inline, overflow });
+
+        assert!(render(false, false).is_none());
+
+        // only overflow, no inline group
+        let cell = render(false, true).unwrap();
+        assert!(child_tags(&cell).is_empty());
+
+        let cell = render(true, true).unwrap();
+        let menu = child_tags(&cell);
+        assert_eq!(attr(menu[0], "class"), Some("pf-c-overflow-menu"));
+        let parts = children(menu[0]);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(children(parts[0]).len(), 1);
+    }
+
+    #[test]
+    fn test_toggle_icon() {
+        let style = |expanded| attr(tag(&render_toggle_icon(expanded)), "style").map(String::from);
+
+        assert_eq!(style(false).as_deref(), Some("transform: rotate(-90deg);"));
+        assert_eq!(style(true).as_deref(), Some("transform: rotate(0);"));
+
+        let icon = render_toggle_icon(true);
+        assert_eq!(attr(tag(&icon), "class"), Some("pf-c-table__toggle-icon"));
+        assert_eq!(
+            tag(&icon).children().first(),
+            Some(&html!({ Icon::AngleDown }))
+        );
+    }
+
+    #[test]
+    fn test_tree() {
+        let mut entries = MockModel::new(2);
+        entries.children = vec![Item(10), Item(11)];
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries,
+            tree: true,
+        });
+        assert_eq!(control_columns(&props), 0);
+
+        let rows = render_tree(&props, props.entries.iter(), 1, &TableState::default());
+        let rows: Vec<_> = rows.iter().map(tag).collect();
+
+        let levels: Vec<_> = rows.iter().map(|row| attr(row, "aria-level")).collect();
+        assert_eq!(levels, vec![Some("1"), Some("2"), Some("2"), Some("1")]);
+
+        // only entries with children have a toggle
+        assert_eq!(attr(rows[0], "aria-expanded"), Some("true"));
+        assert_eq!(attr(rows[1], "aria-expanded"), None);
+        assert_eq!(attr(rows[2], "aria-posinset"), Some("2"));
+        assert_eq!(attr(rows[2], "aria-setsize"), Some("2"));
+
+        let cells = children(rows[0]);
+        assert_eq!(cells.len(), 2);
+        assert_eq!(
+            attr(cells[0], "class"),
+            Some("pf-c-table__tree-view-title-cell")
+        );
+    }
+
+    #[test]
+    fn test_tree_draggable() {
+        let mut entries = MockModel::new(1);
+        entries.children = vec![Item(10)];
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries,
+            tree: true,
+            draggable: true,
+        });
+        assert_eq!(control_columns(&props), 0);
+
+        // the header doesn't have a drag column either
+        let header = super::header(&props, &TableState::default()).unwrap();
+        assert!(!header.props.draggable);
+
+        let rows = render_tree(&props, props.entries.iter(), 1, &TableState::default());
+        for row in &rows {
+            let row = tag(row);
+            // the data cells start at the first column
+            assert_eq!(span(row), data_columns(&props));
+            assert_eq!(attr(row, "draggable"), None);
+        }
+    }
+
+    #[test]
+    fn test_text_align() {
+        let header = html_nested!(
+            <TableHeader<Column>>
+                <TableColumn<Column> index={Column::First} label="First" />
+                <TableColumn<Column> index={Column::Second} label="Second" text_align={TextAlign::Right} />
+            </TableHeader<Column>>
+        );
+
+        let columns: Vec<_> = header
+            .props
+            .children
+            .iter()
+            .map(|column| column::header_classes(&column.props).to_string())
+            .collect();
+        assert_eq!(columns, vec!["".to_string(), "pf-m-right".to_string()]);
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header,
+            entries: MockModel::new(2),
+        });
+        for entry in props.entries.iter() {
+            let cells = render_row(&props, entry.value);
+            assert_eq!(attr(tag(&cells[0]), "class"), None);
+            assert_eq!(attr(tag(&cells[1]), "class"), Some("pf-m-right"));
+        }
+    }
+
+    #[test]
+    fn test_header_content() {
+        let header = html_nested!(
+            <TableHeader<Column>>
+                <TableColumn<Column> index={Column::First} label="First">
+                    { Icon::InfoCircle }
+                </TableColumn<Column>>
+                <TableColumn<Column> index={Column::Second} label="Second" />
+            </TableHeader<Column>>
+        );
+
+        let columns: Vec<_> = header.props.children.iter().collect();
+        assert_eq!(
+            column::header_content(&columns[0].props),
+            Some(html!({ for [html!({ Icon::InfoCircle })] }))
+        );
+        assert_eq!(
+            column::header_content(&columns[1].props),
+            Some(html!("Second"))
+        );
+
+        // the label still labels the cells
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header,
+            entries: MockModel::new(1),
+        });
+        for entry in props.entries.iter() {
+            let cells = render_row(&props, entry.value);
+            assert_eq!(attr(tag(&cells[0]), "data-label"), Some("First"));
+        }
+    }
+
+    #[test]
+    fn test_data_label() {
+        let header = html_nested!(
+            <TableHeader<Column>>
+                <TableColumn<Column> index={Column::First} label="First" />
+                <TableColumn<Column> index={Column::Second} data_label="Second" />
+            </TableHeader<Column>>
+        );
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header,
+            entries: MockModel::new(1),
+        });
+        for entry in props.entries.iter() {
+            let cells = render_row(&props, entry.value);
+            assert_eq!(attr(tag(&cells[0]), "data-label"), Some("First"));
+            assert_eq!(attr(tag(&cells[1]), "data-label"), Some("Second"));
+        }
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: html_nested!(
+                <TableHeader<Column>>
+                    <TableColumn<Column> index={Column::First} />
+                </TableHeader<Column>>
+            ),
+            entries: MockModel::new(1),
+        });
+        for entry in props.entries.iter() {
+            let cells = render_row(&props, entry.value);
+            assert_eq!(attr(tag(&cells[0]), "data-label"), None);
+        }
+    }
+
+    #[test]
+    fn test_reordered_columns() {
+        // the columns in the order requested by a column reorder
+        let header = html_nested!(
+            <TableHeader<Column> reorderable_columns=true>
+                <TableColumn<Column> index={Column::Second} label="Second" />
+                <TableColumn<Column> index={Column::First} label="First" />
+            </TableHeader<Column>>
+        );
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header,
+            entries: MockModel::new(1),
+        });
+        for entry in props.entries.iter() {
+            let cells = render_row(&props, entry.value);
+            assert_eq!(tag(&cells[0]).children().first(), Some(&html!("second")));
+            assert_eq!(tag(&cells[1]).children().first(), Some(&html!(0)));
+        }
+    }
+
+    #[test]
+    fn test_caption() {
+        let caption = |props: &TableProperties<Column, MockModel>| match render_caption(props) {
+            Html::VList(mut list) => list.pop(),
+            _ => None,
+        };
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(0),
+            caption: "Plain",
+        });
+        let plain = caption(&props).unwrap();
+        assert!(matches!(
+            tag(&plain).children().iter().next(),
+            Some(VNode::VText(_))
+        ));
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(0),
+            caption: html!(
+                <>
+                    <h2>{ "Title" }</h2>
+                    <p>{ "Description" }</p>
+                </>
+            ),
+        });
+        let rich = caption(&props).unwrap();
+        assert_eq!(tag(&rich).tag(), "caption");
+        assert_eq!(children(tag(&rich)).len(), 2);
+    }
+
+    #[test]
+    fn test_clamp_spans() {
+        let clamp = |requested: &[usize], cols| clamp_spans(requested.iter().copied(), cols);
+
+        assert_eq!(clamp(&[1, 1], 4), (vec![1, 1], 2));
+        assert_eq!(clamp(&[1, usize::MAX], 4), (vec![1, 3], 0));
+        // over-requesting cells don't drop the following ones
+        assert_eq!(clamp(&[usize::MAX, 1], 4), (vec![3, 1], 0));
+        assert_eq!(clamp(&[2, 5, 1], 3), (vec![1, 1, 1], 0));
+        // more cells than columns
+        assert_eq!(clamp(&[1, 1, 1], 2), (vec![1, 1, 1], 0));
+    }
+
+    #[test]
+    fn test_visibility() {
+        let header = html_nested!(
+            <TableHeader<Column>>
+                <TableColumn<Column> index={Column::First} label="First" />
+                <TableColumn<Column>
+                    index={Column::Second}
+                    label="Second"
+                    visibility={[Visibility::Hidden.all(), Visibility::Visible.lg()]}
+                />
+            </TableHeader<Column>>
+        );
+
+        let columns: Vec<_> = header
+            .props
+            .children
+            .iter()
+            .map(|column| column::header_classes(&column.props).to_string())
+            .collect();
+        assert_eq!(columns[1], "pf-m-hidden pf-m-visible-on-lg");
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header,
+            entries: MockModel::new(1),
+        });
+        for entry in props.entries.iter() {
+            let cells = render_row(&props, entry.value);
+            assert_eq!(attr(tag(&cells[0]), "class"), None);
+            assert_eq!(
+                attr(tag(&cells[1]), "class"),
+                Some("pf-m-hidden pf-m-visible-on-lg")
+            );
+        }
+    }
+
+    #[test]
+    fn test_uncontrolled() {
+        use std::cell::RefCell;
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(2),
+            mode: TableMode::Expandable,
+            controlled: false,
+        });
+
+        // the reducer, as run by the table
+        let actions = Rc::new(RefCell::new(vec![]));
+        let reduce = |expansion: Rc<Expansion>| {
+            actions
+                .borrow_mut()
+                .drain(..)
+                .fold(expansion, |expansion, action| expansion.reduce(action))
+        };
+        let state = |expansion: &Rc<Expansion>| TableState {
+            uncontrolled: Some(Uncontrolled {
+                expansion: expansion.clone(),
+                dispatch: {
+                    let actions = actions.clone();
+                    Callback::from(move |action| actions.borrow_mut().push(action))
+                },
+            }),
+            ..Default::default()
+        };
+        let expanded = |state: &TableState| {
+            props
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| {
+                    let body = render_expandable_entry(&props, index, entry, &Rc::from([]), state);
+                    attr(tag(&body), "class") == Some("pf-m-expanded")
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // the model reports all entries as expanded, but nothing was expanded yet
+        let expansion = Rc::new(Expansion::default());
+        assert_eq!(expanded(&state(&expansion)), vec![false, false]);
+
+        // toggle the second row
+        state(&expansion).onexpand(&props.onexpand).emit((1, true));
+        let expansion = reduce(expansion);
+        assert_eq!(expanded(&state(&expansion)), vec![false, true]);
+
+        // and close it again
+        state(&expansion).onexpand(&props.onexpand).emit((1, false));
+        let expansion = reduce(expansion);
+        assert_eq!(expanded(&state(&expansion)), vec![false, false]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(0),
+            mode: TableMode::Expandable,
+            empty: html!("Nothing here"),
+        });
+
+        let body = render_entries(&props, &TableState::default());
+        let row = child_tags(&body)[0];
+        let cells = children(row);
+
+        // toggle, two data columns, actions
+        assert_eq!(cells.len(), 1);
+        assert_eq!(attr(cells[0], "colspan"), Some("4"));
+    }
+
+    #[test]
+    fn test_role() {
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(2),
+        });
+        assert_eq!(table_role(&props), "table");
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(2),
+            expandable: true,
+        });
+        assert_eq!(table_role(&props), "grid");
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: html_nested!(
+                <TableHeader<Column>>
+                    <TableColumn<Column> index={Column::First} label="First" sortable=true />
+                </TableHeader<Column>>
+            ),
+            entries: MockModel::new(2),
+        });
+        assert_eq!(table_role(&props), "grid");
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            entries: MockModel::new(2),
+            tree: true,
+        });
+        assert_eq!(table_role(&props), "treegrid");
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            entries: MockModel::new(2),
+            expandable: true,
+            role: AttrValue::from("presentation"),
+        });
+        assert_eq!(table_role(&props), "presentation");
+    }
+
+    #[test]
+    fn test_footer() {
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(2),
+            selection_mode: TableSelectionMode::Multiple,
+        });
+        assert!(footer(&props).is_none());
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            footer: html_nested!(<TableFooter>{ "Total" }</TableFooter>),
+            entries: MockModel::new(2),
+            selection_mode: TableSelectionMode::Multiple,
+        });
+        let footer = footer(&props).unwrap();
+        assert_eq!(footer.props.control_columns, 1);
+        assert_eq!(footer.props.data_columns, 2);
+        assert!(footer.props.actions);
+    }
+
+    #[test]
+    fn test_modifiers() {
+        let classes = |mode, compact, borders, expandable| {
+            let props = yew::props!(TableProperties<Column, MockModel> {
+                entries: MockModel::new(1),
+                mode,
+                compact,
+                borders,
+                expandable,
+            });
+            table_classes(&props).to_string()
+        };
+
+        assert_eq!(
+            classes(TableMode::Default, false, true, false),
+            "pf-c-table"
+        );
+        assert_eq!(
+            classes(TableMode::Default, true, true, false),
+            "pf-c-table pf-m-compact"
+        );
+        assert_eq!(
+            classes(TableMode::Default, false, false, true),
+            "pf-c-table pf-m-expandable pf-m-no-border-rows"
+        );
+        assert_eq!(
+            classes(TableMode::Default, true, false, true),
+            "pf-c-table pf-m-compact pf-m-expandable pf-m-no-border-rows"
+        );
+
+        // the modes map onto the flags, and combine with them
+        assert_eq!(
+            classes(TableMode::Compact, false, true, false),
+            classes(TableMode::Default, true, true, false)
+        );
+        assert_eq!(
+            classes(TableMode::CompactNoBorders, false, true, false),
+            classes(TableMode::Default, true, false, false)
+        );
+        assert_eq!(
+            classes(TableMode::CompactExpandable, false, true, false),
+            classes(TableMode::Default, true, true, true)
+        );
+        assert_eq!(
+            classes(TableMode::Expandable, false, false, false),
+            classes(TableMode::Default, false, false, true)
+        );
+    }
+
+    #[test]
+    fn test_virtual_rows() {
+        let viewport = |scroll_top, height| Viewport {
+            scroll_top,
+            height,
+            offset: 0,
+        };
+
+        assert_eq!(virtual_rows(&viewport(0, 0), 50, 2, 100), 0..2);
+        assert_eq!(virtual_rows(&viewport(0, 200), 50, 2, 100), 0..6);
+        // scrolled down by 10 rows, and a bit
+        assert_eq!(virtual_rows(&viewport(520, 200), 50, 2, 100), 8..16);
+        assert_eq!(virtual_rows(&viewport(4900, 200), 50, 2, 100), 96..100);
+        assert_eq!(virtual_rows(&viewport(0, 200), 0, 2, 100), 0..100);
+
+        // the header takes the first 60 pixels of the scrolled content
+        let viewport = |scroll_top| Viewport {
+            scroll_top,
+            height: 200,
+            offset: 60,
+        };
+        assert_eq!(virtual_rows(&viewport(0), 50, 2, 100), 0..6);
+        assert_eq!(virtual_rows(&viewport(40), 50, 2, 100), 0..6);
+        assert_eq!(virtual_rows(&viewport(580), 50, 2, 100), 8..16);
+    }
+
+    #[test]
+    fn test_virtualized() {
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(100),
+            virtualized: true,
+            row_height: 50,
+        });
+        assert!(is_virtualized(&props));
+
+        let state = TableState {
+            window: Some(20..30),
+            ..Default::default()
+        };
+        let body = render_entries(&props, &state);
+        let rows = match &body {
+            VNode::VList(list) => child_tags(&list[0]),
+            body => child_tags(body),
+        };
+
+        // two spacers, ten rows, spacer
+        assert_eq!(rows.len(), 13);
+        assert_eq!(attr(rows[0], "style"), Some("height: 950px;"));
+        assert_eq!(attr(rows[1], "style"), Some("height: 50px;"));
+        assert_eq!(attr(rows[12], "style"), Some("height: 3500px;"));
+    }
+
+    #[test]
+    fn test_virtualized_striped() {
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(100),
+            virtualized: true,
+            row_height: 50,
+            striped: true,
+        });
+
+        assert_eq!(leading_spacers(0), Vec::<usize>::new());
+        assert_eq!(leading_spacers(1), vec![1]);
+        assert_eq!(leading_spacers(2), vec![1, 1]);
+        assert_eq!(leading_spacers(21), vec![21]);
+
+        for start in 0..4 {
+            let state = TableState {
+                window: Some(start..start + 10),
+                ..Default::default()
+            };
+            let body = render_entries(&props, &state);
+            let rows = match &body {
+                VNode::VList(list) => child_tags(&list[0]),
+                body => child_tags(body),
+            };
+
+            // the rows are striped by their position, which must match their index
+            let first = rows
+                .iter()
+                .position(|row| attr(row, "aria-hidden").is_none())
+                .unwrap();
+            assert_eq!(first % 2, start % 2, "{start}");
+        }
+    }
+
+    #[test]
+    fn test_loading() {
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(5),
+            mode: TableMode::Expandable,
+            loading: true,
+        });
+
+        let body = render_entries(&props, &TableState::default());
+        let rows = child_tags(&body);
+
+        assert_eq!(rows.len(), 3);
+        for row in rows {
+            let cells = children(row);
+            assert_eq!(cells.len(), 4);
+            // no toggle, no actions
+            assert!(children(cells[0]).is_empty());
+            assert!(children(cells[3]).is_empty());
+            assert_eq!(attr(children(cells[1])[0], "class"), Some("pf-c-skeleton"));
+        }
+    }
+
+    #[test]
+    fn test_compound() {
+        let header = html_nested!(
+            <TableHeader<Column>>
+                <TableColumn<Column> index={Column::First} label="First" />
+                <TableColumn<Column> index={Column::Second} label="Second" compound=true />
+            </TableHeader<Column>>
+        );
+
+        let mut entries = MockModel::new(1);
+        entries.compound = Some(Column::Second);
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header,
+            entries,
+        });
+
+        let entry = props.entries.iter().next().unwrap();
+        let body = render_compound_entry(&props, 0, entry, &TableState::default());
+        assert_eq!(attr(tag(&body), "class"), Some("pf-m-expanded"));
+
+        let rows = child_tags(&body);
+        assert_eq!(rows.len(), 2);
+
+        let cells = children(rows[0]);
+        assert_eq!(cells.len(), 2);
+        assert_eq!(
+            attr(cells[1], "class"),
+            Some("pf-c-table__compound-expansion-toggle pf-m-expanded")
+        );
+
+        let details = children(rows[1]);
+        assert_eq!(details.len(), 1);
+        assert_eq!(span(rows[1]), total_columns(&props));
+    }
+
+    #[test]
+    fn test_sticky_columns() {
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(1),
+            grid: TableGridMode::Medium,
+            sticky_columns: 1,
+        });
+
+        let entry = props.entries.iter().next().unwrap();
+        let cells = render_row(&props, entry.value);
+        let classes: Vec<_> = cells
+            .iter()
+            .map(|cell| attr(tag(cell), "class").unwrap_or_default().to_string())
+            .collect();
+
+        assert_eq!(
+            classes,
+            vec![
+                "pf-m-sticky-column pf-m-border-right".to_string(),
+                String::new()
+            ]
+        );
+        assert_eq!(
+            attr(tag(&cells[0]), "style"),
+            Some("--pf-c-table__sticky-column--Left: 0%;")
+        );
+
+        // the offset must not place the column over the selection cell
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(1),
+            grid: TableGridMode::Medium,
+            sticky_columns: 1,
+            selection_mode: TableSelectionMode::Multiple,
+        });
+        let sticky = sticky_data_columns(&props);
+        assert_eq!(sticky.len(), 1);
+        assert_eq!(sticky[0].apply(&mut Classes::new()), None);
+    }
+
+    #[test]
+    fn test_clickable_rows() {
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(1),
+        });
+        let entry = props.entries.iter().next().unwrap();
+        let row = render_normal_entry(&props, 0, entry, &TableState::default());
+        assert_eq!(attr(tag(&row), "class"), None);
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(1),
+            clickable_rows: true,
+        });
+        let entry = props.entries.iter().next().unwrap();
+        let row = render_normal_entry(&props, 0, entry, &TableState::default());
+        assert_eq!(attr(tag(&row), "class"), Some("pf-m-hoverable"));
+    }
+
+    #[test]
+    fn test_row_class() {
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel {
+                danger: Some(1),
+                ..MockModel::new(2)
+            },
+            clickable_rows: true,
+        });
+
+        let rows: Vec<_> = props
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| render_normal_entry(&props, index, entry, &TableState::default()))
+            .collect();
+        assert_eq!(attr(tag(&rows[0]), "class"), Some("pf-m-hoverable"));
+        assert_eq!(
+            attr(tag(&rows[1]), "class"),
+            Some("pf-m-hoverable pf-m-danger")
+        );
+
+        let column = yew::props!(TableColumnProperties<Column> { index: Column::First });
+        let cell = Cell::new(html!()).center().class("custom");
+        let cell = render_cell(&column, None, cell, Classes::new());
+        assert_eq!(attr(tag(&cell), "class"), Some("pf-m-center custom"));
+    }
+
+    #[test]
+    fn test_active() {
+        let rows = |props: &TableProperties<Column, MockModel>| -> Vec<Option<String>> {
+            props
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| {
+                    render_normal_entry(props, index, entry, &TableState::default())
+                })
+                .map(|row| attr(tag(&row), "class").map(ToString::to_string))
+                .collect()
+        };
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(3),
+            selection_mode: TableSelectionMode::Single,
+            active: 1,
+        });
+        assert_eq!(rows(&props), vec![None, Some("pf-m-selected".into()), None]);
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(2),
+            clickable_rows: true,
+            active: 0,
+        });
+        assert_eq!(
+            rows(&props),
+            vec![
+                Some("pf-m-hoverable pf-m-selected".into()),
+                Some("pf-m-hoverable".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_striped() {
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(2),
+            striped: true,
+        });
+        assert!(!is_grouped(&props));
+        // the table is striped, not the rows
+        let entry = props.entries.iter().next().unwrap();
+        let row = render_normal_entry(&props, 0, entry, &TableState::default());
+        assert_eq!(attr(tag(&row), "class"), None);
+
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel::new(2),
+            mode: TableMode::Expandable,
+            striped: true,
+        });
+        assert!(is_grouped(&props));
+        for (index, entry) in props.entries.iter().enumerate() {
+            let body = render_expandable_entry(
+                &props,
+                index,
+                entry,
+                &Rc::from([]),
+                &TableState::default(),
+            );
+            for row in child_tags(&body) {
+                let class = attr(row, "class").unwrap_or_default();
+                assert_eq!(
+                    class.contains("pf-m-striped"),
+                    index == 0,
+                    "{index}: {class}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_selection_checked() {
+        let props = yew::props!(TableProperties<Column, MockModel> {
+            header: header(),
+            entries: MockModel { selected: true, ..MockModel::new(1) },
+            selection_mode: TableSelectionMode::Multiple,
+        });
+
+        let entry = props.entries.iter().next().unwrap();
+        let row = render_normal_entry(&props, 0, entry, &TableState::default());
+        let cells = child_tags(&row);
+        let input = children(cells[0])[0];
+
+        assert_eq!(attr(cells[0], "class"), Some("pf-c-table__check"));
+        assert_eq!(attr(input, "type"), Some("checkbox"));
+        assert_eq!(input.checked(), Some(true));
+    }
+}
@@ -0,0 +1,380 @@
+use super::{StateModelIter, TableModel, TableModelEntry};
+use crate::next::TableEntryRenderer;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::rc::Rc;
+use yew::virtual_dom::Key;
+use yew::Classes;
+
+/// Derive a key, from the index and value of an entry.
+type KeyFn<T, K> = Rc<dyn Fn(usize, &T) -> K>;
+
+/// A [`TableModel`] backed by a `Vec`, keeping track of the expansion, selection, and favorite state.
+///
+/// Entries are identified by a key, which defaults to the index of the entry. When using
+/// [`MemoryTableModel::with_key`], the key is derived from the entry itself, which keeps the state
+/// stable when entries get reordered.
+///
+/// Using [`MemoryTableModel::with_single_expand`], the model only allows a single entry to be
+/// expanded, matching [`TableProperties::single_expand`](crate::next::TableProperties::single_expand).
+///
+/// The model is cheap to clone, as the entries are shared. Mutating the state, creates a new
+/// version of the model, which is then considered to be different from the original one.
+///
+/// ## Example
+///
+/// ```rust
+/// use patternfly_yew::{next::*, prelude::TableMode};
+/// use yew::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq)]
+/// enum Column { Name }
+///
+/// struct Item { name: String }
+///
+/// impl TableEntryRenderer<Column> for Item {
+///     fn render_cell(&self, _: &CellContext<'_, Column>) -> Cell {
+///         html!(&self.name).into()
+///     }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///     let entries = use_state(|| MemoryTableModel::new(vec![
+///         Item { name: "Foo".into() },
+///         Item { name: "Bar".into() },
+///     ]));
+///
+///     let onexpand = {
+///         let entries = entries.clone();
+///         Callback::from(move |(key, expanded)| {
+///             let mut model = (*entries).clone();
+///             model.set_expanded(key, expanded);
+///             entries.set(model);
+///         })
+///     };
+///
+///     let header = html_nested!(
+///         <TableHeader<Column>>
+///             <TableColumn<Column> label="Name" index={Column::Name} />
+///         </TableHeader<Column>>
+///     );
+///
+///     html!(
+///         <Table<Column, MemoryTableModel<Item>>
+///             mode={TableMode::Expandable}
+///             {header} entries={(*entries).clone()} {onexpand}
+///         />
+///     )
+/// }
+/// ```
+pub struct MemoryTableModel<T, K = usize>
+where
+    K: Into<Key> + Clone + Eq + Hash + 'static,
+{
+    entries: Rc<Vec<T>>,
+    key: KeyFn<T, K>,
+    expanded: HashSet<K>,
+    selected: HashSet<K>,
+    favorites: HashSet<K>,
+    single_expand: bool,
+}
+
+impl<T> MemoryTableModel<T, usize> {
+    /// Create a new model, using the index of an entry as its key.
+    pub fn new(entries: impl Into<Rc<Vec<T>>>) -> Self {
+        Self::with_key(entries, |index, _| index)
+    }
+}
+
+impl<T> Default for MemoryTableModel<T, usize> {
+    fn default() -> Self {
+        Self::new(vec![])
+    }
+}
+
+impl<T, K> MemoryTableModel<T, K>
+where
+    K: Into<Key> + Clone + Eq + Hash + 'static,
+{
+    /// Create a new model, using a function to derive the key of an entry.
+    pub fn with_key<F>(entries: impl Into<Rc<Vec<T>>>, key: F) -> Self
+    where
+        F: Fn(usize, &T) -> K + 'static,
+    {
+        Self {
+            entries: entries.into(),
+            key: Rc::new(key),
+            expanded: Default::default(),
+            selected: Default::default(),
+            favorites: Default::default(),
+            single_expand: false,
+        }
+    }
+
+    /// Only allow a single entry to be expanded.
+    ///
+    /// Expanding an entry will collapse all other entries.
+    pub fn with_single_expand(mut self, single_expand: bool) -> Self {
+        self.single_expand = single_expand;
+        if single_expand && self.expanded.len() > 1 {
+            self.expanded.clear();
+        }
+        self
+    }
+
+    /// Get the entries of the model.
+    pub fn entries(&self) -> &[T] {
+        &self.entries
+    }
+
+    /// Replace the entries, keeping the state of all keys which are still present.
+    pub fn set_entries(&mut self, entries: impl Into<Rc<Vec<T>>>) {
+        self.entries = entries.into();
+
+        let keys = self.keys().collect::<HashSet<_>>();
+        self.expanded.retain(|key| keys.contains(key));
+        self.selected.retain(|key| keys.contains(key));
+        self.favorites.retain(|key| keys.contains(key));
+    }
+
+    fn keys(&self) -> impl Iterator<Item = K> + '_ {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (self.key)(index, entry))
+    }
+
+    pub fn is_expanded(&self, key: &K) -> bool {
+        self.expanded.contains(key)
+    }
+
+    pub fn set_expanded(&mut self, key: K, expanded: bool) {
+        match expanded {
+            true => {
+                if self.single_expand {
+                    self.expanded.clear();
+                }
+                self.expanded.insert(key)
+            }
+            false => self.expanded.remove(&key),
+        };
+    }
+
+    /// Toggle the expansion state of an entry, returning the new state.
+    pub fn toggle_expanded(&mut self, key: K) -> bool {
+        let expanded = !self.is_expanded(&key);
+        self.set_expanded(key, expanded);
+        expanded
+    }
+
+    /// Expand, or collapse, all entries.
+    ///
+    /// In single expand mode, entries can only be collapsed.
+    pub fn expand_all(&mut self, expanded: bool) {
+        self.expanded = match expanded && !self.single_expand {
+            true => self.keys().collect(),
+            false => Default::default(),
+        };
+    }
+
+    pub fn is_selected(&self, key: &K) -> bool {
+        self.selected.contains(key)
+    }
+
+    pub fn set_selected(&mut self, key: K, selected: bool) {
+        match selected {
+            true => self.selected.insert(key),
+            false => self.selected.remove(&key),
+        };
+    }
+
+    pub fn is_favorite(&self, key: &K) -> bool {
+        self.favorites.contains(key)
+    }
+
+    pub fn set_favorite(&mut self, key: K, favorite: bool) {
+        match favorite {
+            true => self.favorites.insert(key),
+            false => self.favorites.remove(&key),
+        };
+    }
+
+    /// Select, or de-select, all entries.
+    pub fn select_all(&mut self, selected: bool) {
+        self.selected = match selected {
+            true => self.keys().collect(),
+            false => Default::default(),
+        };
+    }
+}
+
+impl<T, K> Clone for MemoryTableModel<T, K>
+where
+    K: Into<Key> + Clone + Eq + Hash + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            key: self.key.clone(),
+            expanded: self.expanded.clone(),
+            selected: self.selected.clone(),
+            favorites: self.favorites.clone(),
+            single_expand: self.single_expand,
+        }
+    }
+}
+
+impl<T, K> PartialEq for MemoryTableModel<T, K>
+where
+    K: Into<Key> + Clone + Eq + Hash + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.entries, &other.entries)
+            && self.expanded == other.expanded
+            && self.selected == other.selected
+            && self.favorites == other.favorites
+            && self.single_expand == other.single_expand
+    }
+}
+
+impl<C, T, K> TableModel<C> for MemoryTableModel<T, K>
+where
+    C: Clone + Eq + 'static,
+    T: TableEntryRenderer<C> + 'static,
+    K: Into<Key> + Clone + Eq + Hash + 'static,
+{
+    type Iterator<'i> = StateModelIter<'i, K, T>;
+    type Item = T;
+    type Key = K;
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        StateModelIter::new(
+            self.entries
+                .as_slice()
+                .iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    let key = (self.key)(index, value);
+                    TableModelEntry {
+                        expanded: self.expanded.contains(&key),
+                        selected: self.selected.contains(&key),
+                        favorite: self.favorites.contains(&key),
+                        row_class: Classes::new(),
+                        key,
+                        value,
+                    }
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::next::{Cell, CellContext};
+    use yew::html;
+
+    struct Item(&'static str);
+
+    impl TableEntryRenderer<()> for Item {
+        fn render_cell(&self, _: &CellContext<'_, ()>) -> Cell {
+            html!(self.0).into()
+        }
+    }
+
+    fn state(model: &MemoryTableModel<Item, &'static str>) -> Vec<(&'static str, bool, bool)> {
+        TableModel::<()>::iter(model)
+            .map(|entry| (entry.key, entry.expanded, entry.selected))
+            .collect()
+    }
+
+    #[test]
+    fn test_state() {
+        let mut model =
+            MemoryTableModel::with_key(vec![Item("a"), Item("b"), Item("c")], |_, item| item.0);
+
+        model.set_expanded("b", true);
+        assert!(model.toggle_expanded("c"));
+        model.set_selected("a", true);
+
+        assert_eq!(
+            state(&model),
+            vec![("a", false, true), ("b", true, false), ("c", true, false)]
+        );
+
+        assert!(!model.toggle_expanded("c"));
+        model.select_all(true);
+        assert_eq!(
+            state(&model),
+            vec![("a", false, true), ("b", true, true), ("c", false, true)]
+        );
+
+        model.expand_all(true);
+        assert!(state(&model).iter().all(|(_, expanded, _)| *expanded));
+        model.expand_all(false);
+        assert!(state(&model).iter().all(|(_, expanded, _)| !*expanded));
+    }
+
+    #[test]
+    fn test_single_expand() {
+        let mut model =
+            MemoryTableModel::with_key(vec![Item("a"), Item("b"), Item("c")], |_, item| item.0)
+                .with_single_expand(true);
+
+        model.set_expanded("a", true);
+        assert!(model.toggle_expanded("b"));
+        assert_eq!(
+            state(&model),
+            vec![("a", false, false), ("b", true, false), ("c", false, false)]
+        );
+
+        // collapsing a row doesn't affect others
+        model.set_expanded("a", false);
+        assert!(model.is_expanded(&"b"));
+
+        assert!(!model.toggle_expanded("b"));
+        assert_eq!(
+            state(&model),
+            vec![
+                ("a", false, false),
+                ("b", false, false),
+                ("c", false, false)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_entries() {
+        let mut model = MemoryTableModel::with_key(vec![Item("a"), Item("b")], |_, item| item.0);
+        model.set_expanded("a", true);
+        model.set_expanded("b", true);
+        model.set_favorite("a", true);
+        model.set_favorite("b", true);
+
+        // "a" is gone, "b" must still be expanded
+        model.set_entries(vec![Item("b"), Item("c")]);
+        assert!(!model.is_expanded(&"a"));
+        assert!(!model.is_favorite(&"a"));
+        assert!(model.is_favorite(&"b"));
+        assert_eq!(state(&model), vec![("b", true, false), ("c", false, false)]);
+    }
+
+    #[test]
+    fn test_eq() {
+        let m1 = MemoryTableModel::new(vec![Item("a")]);
+        let mut m2 = m1.clone();
+        assert!(m1 == m2);
+
+        m2.set_expanded(0, true);
+        assert!(m1 != m2);
+    }
+}
@@ -1,16 +1,21 @@
 mod hook;
 mod memoized;
+mod memory;
+mod paginated;
 mod state;
 mod table;
 
 pub use hook::*;
 pub use memoized::*;
+pub use memory::*;
+pub use paginated::*;
 pub use state::*;
 pub use table::*;
 
 use super::TableEntryRenderer;
 use std::rc::Rc;
 use yew::virtual_dom::Key;
+use yew::Classes;
 
 /// A model providing data for a table.
 pub trait TableModel<C>
@@ -33,6 +38,22 @@ where
 
     /// Iterate over all the items
     fn iter(&self) -> Self::Iterator<'_>;
+
+    /// Iterate over the children of an entry, when rendering a tree.
+    ///
+    /// Returns [`None`] if the entry has no children. Defaults to no children.
+    fn children(&self, key: &Self::Key) -> Option<Self::Iterator<'_>> {
+        let _ = key;
+        None
+    }
+
+    /// Get the column whose compound expandable content is shown for an entry.
+    ///
+    /// Defaults to no compound expanded column.
+    fn compound_expanded(&self, key: &Self::Key) -> Option<C> {
+        let _ = key;
+        None
+    }
 }
 
 impl<C, M> TableModel<C> for Rc<M>
@@ -55,6 +76,49 @@ where
     fn iter(&self) -> Self::Iterator<'_> {
         self.as_ref().iter()
     }
+
+    fn children(&self, key: &Self::Key) -> Option<Self::Iterator<'_>> {
+        self.as_ref().children(key)
+    }
+
+    fn compound_expanded(&self, key: &Self::Key) -> Option<C> {
+        self.as_ref().compound_expanded(key)
+    }
+}
+
+/// A plain list of entries, keyed by their index.
+///
+/// The entries are neither expanded, selected, nor favorites, unless the [`Table`](crate::next::Table)
+/// manages the expansion itself.
+impl<C, T> TableModel<C> for Vec<T>
+where
+    C: Clone + Eq + 'static,
+    T: TableEntryRenderer<C> + 'static,
+{
+    type Iterator<'i> = StateModelIter<'i, usize, T>;
+    type Item = T;
+    type Key = usize;
+
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        StateModelIter::new(self.as_slice().iter().enumerate().map(|(key, value)| {
+            TableModelEntry {
+                value,
+                key,
+                expanded: false,
+                selected: false,
+                favorite: false,
+                row_class: Classes::new(),
+            }
+        }))
+    }
 }
 
 pub trait TableDataModel<C>
@@ -115,4 +179,36 @@ where
     pub value: &'t T,
     pub key: K,
     pub expanded: bool,
+    pub selected: bool,
+    /// If the entry is marked as favorite, see [`TableProperties::favorites`](crate::next::TableProperties::favorites).
+    pub favorite: bool,
+    /// Additional classes of the row, e.g. `pf-m-danger` for highlighting an entry.
+    pub row_class: Classes,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::next::{Cell, CellContext};
+    use yew::html;
+
+    #[derive(PartialEq)]
+    struct Item(&'static str);
+
+    impl TableEntryRenderer<()> for Item {
+        fn render_cell(&self, _: &CellContext<'_, ()>) -> Cell {
+            html!(self.0).into()
+        }
+    }
+
+    #[test]
+    fn test_vec() {
+        let model = vec![Item("a"), Item("b")];
+
+        assert_eq!(TableModel::<()>::len(&model), 2);
+        let entries: Vec<_> = TableModel::<()>::iter(&model)
+            .map(|entry| (entry.key, entry.value.0, entry.expanded))
+            .collect();
+        assert_eq!(entries, vec![(0, "a", false), (1, "b", false)]);
+    }
 }
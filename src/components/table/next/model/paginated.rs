@@ -0,0 +1,248 @@
+use super::{StateModelIter, TableModel};
+use crate::prelude::Navigation;
+
+/// A [`TableModel`] showing a single page of an inner model.
+///
+/// The table only sees the entries of the current page, while the keys, and the state of the
+/// entries, are those of the inner model. The page can be driven by a
+/// [`Pagination`](crate::prelude::Pagination) component, using [`PaginatedTableModel::navigate`]
+/// from its `onnavigation` callback, and [`PaginatedTableModel::set_limit`] from its `onlimit`
+/// callback.
+///
+/// ## Example
+///
+/// ```rust
+/// use patternfly_yew::{next::*, prelude::*};
+/// use yew::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq)]
+/// enum Column { Name }
+///
+/// struct Item { name: String }
+///
+/// impl TableEntryRenderer<Column> for Item {
+///     fn render_cell(&self, _: &CellContext<'_, Column>) -> Cell {
+///         html!(&self.name).into()
+///     }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///     let entries = use_state(|| {
+///         let items = (0..100).map(|i| Item { name: format!("Item {i}") }).collect::<Vec<_>>();
+///         PaginatedTableModel::new(MemoryTableModel::new(items), 0, 10)
+///     });
+///
+///     let onnavigation = {
+///         let entries = entries.clone();
+///         Callback::from(move |navigation| {
+///             let mut model = (*entries).clone();
+///             model.navigate(navigation);
+///             entries.set(model);
+///         })
+///     };
+///
+///     let header = html_nested!(
+///         <TableHeader<Column>>
+///             <TableColumn<Column> label="Name" index={Column::Name} />
+///         </TableHeader<Column>>
+///     );
+///
+///     html!(
+///         <>
+///             <Pagination
+///                 total_entries={entries.total()}
+///                 offset={entries.offset()}
+///                 selected_choice={entries.limit()}
+///                 {onnavigation}
+///             />
+///             <Table<Column, PaginatedTableModel<MemoryTableModel<Item>>> {header} entries={(*entries).clone()} />
+///         </>
+///     )
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaginatedTableModel<M> {
+    model: M,
+    offset: usize,
+    limit: usize,
+}
+
+impl<M> PaginatedTableModel<M> {
+    /// Show `limit` entries of the model, starting at `offset`.
+    pub fn new(model: M, offset: usize, limit: usize) -> Self {
+        Self {
+            model,
+            offset,
+            limit,
+        }
+    }
+
+    /// The inner model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// The inner model, for changing its state.
+    pub fn model_mut(&mut self) -> &mut M {
+        &mut self.model
+    }
+
+    /// The offset of the first entry of the current page.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The maximum number of entries of a page.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Change the number of entries of a page, staying on the page of the current offset.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+        if let Some(page) = self.offset.checked_div(limit) {
+            self.offset = page * limit;
+        }
+    }
+}
+
+impl<M> PaginatedTableModel<M> {
+    /// The number of entries of the inner model.
+    pub fn total<C>(&self) -> usize
+    where
+        C: Clone + Eq + 'static,
+        M: TableModel<C>,
+    {
+        self.model.len()
+    }
+
+    /// Navigate to a different page, as requested by a [`Pagination`](crate::prelude::Pagination).
+    ///
+    /// Pages requested by number start with `1`.
+    pub fn navigate<C>(&mut self, navigation: Navigation)
+    where
+        C: Clone + Eq + 'static,
+        M: TableModel<C>,
+    {
+        self.offset = page_offset(self.offset, self.limit, self.model.len(), navigation);
+    }
+}
+
+/// Evaluate the offset of the page, after navigating.
+fn page_offset(offset: usize, limit: usize, total: usize, navigation: Navigation) -> usize {
+    if limit == 0 {
+        return 0;
+    }
+
+    let last = total.saturating_sub(1) / limit * limit;
+
+    match navigation {
+        Navigation::First => 0,
+        Navigation::Previous => offset.saturating_sub(limit),
+        Navigation::Next => (offset + limit).min(last),
+        Navigation::Last => last,
+        Navigation::Page(page) => (page.saturating_sub(1) * limit).min(last),
+    }
+}
+
+impl<C, M> TableModel<C> for PaginatedTableModel<M>
+where
+    C: Clone + Eq + 'static,
+    M: TableModel<C>,
+{
+    type Iterator<'i>
+        = StateModelIter<'i, Self::Key, Self::Item>
+    where
+        Self: 'i;
+    type Item = M::Item;
+    type Key = M::Key;
+
+    fn len(&self) -> usize {
+        self.model.len().saturating_sub(self.offset).min(self.limit)
+    }
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        StateModelIter::new(self.model.iter().skip(self.offset).take(self.limit))
+    }
+
+    fn children(&self, key: &Self::Key) -> Option<Self::Iterator<'_>> {
+        // only the first level is paginated, children are shown in full
+        self.model.children(key).map(StateModelIter::new)
+    }
+
+    fn compound_expanded(&self, key: &Self::Key) -> Option<C> {
+        self.model.compound_expanded(key)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::next::{Cell, CellContext, MemoryTableModel, TableEntryRenderer};
+    use yew::html;
+
+    #[derive(PartialEq)]
+    struct Item(&'static str);
+
+    impl TableEntryRenderer<()> for Item {
+        fn render_cell(&self, _: &CellContext<'_, ()>) -> Cell {
+            html!(self.0).into()
+        }
+    }
+
+    fn keys(
+        model: &PaginatedTableModel<MemoryTableModel<Item, &'static str>>,
+    ) -> Vec<&'static str> {
+        TableModel::<()>::iter(model)
+            .map(|entry| entry.key)
+            .collect()
+    }
+
+    #[test]
+    fn test_window() {
+        let items = vec![Item("a"), Item("b"), Item("c"), Item("d"), Item("e")];
+        let mut model =
+            PaginatedTableModel::new(MemoryTableModel::with_key(items, |_, item| item.0), 0, 2);
+
+        assert_eq!(keys(&model), vec!["a", "b"]);
+        assert_eq!(TableModel::<()>::len(&model), 2);
+
+        model.navigate::<()>(Navigation::Next);
+        assert_eq!(keys(&model), vec!["c", "d"]);
+
+        model.navigate::<()>(Navigation::Last);
+        assert_eq!(keys(&model), vec!["e"]);
+        assert_eq!(TableModel::<()>::len(&model), 1);
+        assert_eq!(model.total::<()>(), 5);
+    }
+
+    #[test]
+    fn test_stable_keys() {
+        let items = vec![Item("a"), Item("b"), Item("c"), Item("d")];
+        let mut model =
+            PaginatedTableModel::new(MemoryTableModel::with_key(items, |_, item| item.0), 0, 2);
+        model.model_mut().set_expanded("c", true);
+
+        model.navigate::<()>(Navigation::Page(2));
+        let entries: Vec<_> = TableModel::<()>::iter(&model)
+            .map(|entry| (entry.key, entry.expanded))
+            .collect();
+        assert_eq!(entries, vec![("c", true), ("d", false)]);
+
+        model.navigate::<()>(Navigation::Previous);
+        assert_eq!(keys(&model), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_page_offset() {
+        assert_eq!(page_offset(0, 10, 25, Navigation::Next), 10);
+        assert_eq!(page_offset(20, 10, 25, Navigation::Next), 20);
+        assert_eq!(page_offset(5, 10, 25, Navigation::Previous), 0);
+        assert_eq!(page_offset(0, 10, 25, Navigation::Last), 20);
+        assert_eq!(page_offset(0, 10, 20, Navigation::Last), 10);
+        assert_eq!(page_offset(0, 10, 0, Navigation::Last), 0);
+        assert_eq!(page_offset(0, 10, 25, Navigation::Page(9)), 20);
+        assert_eq!(page_offset(20, 10, 25, Navigation::First), 0);
+    }
+}
@@ -5,6 +5,7 @@ use std::hash::Hash;
 use std::marker::PhantomData;
 use std::rc::Rc;
 use yew::virtual_dom::Key;
+use yew::Classes;
 
 /// A [`super::TableModel`] based on a [`TableDataModel`] plus additional state.
 pub struct StateModel<C, M>
@@ -68,6 +69,9 @@ where
                 key,
                 value,
                 expanded,
+                selected: false,
+                favorite: false,
+                row_class: Classes::new(),
             }
         }))
     }
@@ -1,7 +1,163 @@
 use super::{Cell, CellContext};
 use crate::prelude::{DropdownChildVariant, Span};
+use yew::html::IntoPropValue;
+use yew::prelude::*;
+
+/// A rendered cell, which might toggle compound expandable content.
+#[derive(Debug)]
+pub enum CompoundCell {
+    /// A regular cell.
+    Cell(Cell),
+    /// A cell toggling the compound expandable content of its row.
+    Toggle {
+        /// The content of the cell itself.
+        cell: Cell,
+        /// The content shown when this cell is expanded.
+        details: Html,
+    },
+}
+
+impl From<Cell> for CompoundCell {
+    fn from(cell: Cell) -> Self {
+        Self::Cell(cell)
+    }
+}
+
+/// The actions of a table entry.
+///
+/// Inline actions, like buttons, are rendered directly in the actions cell, while overflow actions
+/// are rendered in a kebab dropdown.
+#[derive(Default)]
+pub struct TableEntryActions {
+    pub inline: Vec<Html>,
+    pub overflow: Vec<DropdownChildVariant>,
+}
+
+impl TableEntryActions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an inline action.
+    pub fn inline(mut self, action: impl Into<Html>) -> Self {
+        self.inline.push(action.into());
+        self
+    }
+
+    /// Add an overflow action.
+    pub fn overflow(mut self, action: impl Into<DropdownChildVariant>) -> Self {
+        self.overflow.push(action.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inline.is_empty() && self.overflow.is_empty()
+    }
+}
+
+impl From<Vec<DropdownChildVariant>> for TableEntryActions {
+    fn from(overflow: Vec<DropdownChildVariant>) -> Self {
+        Self {
+            inline: vec![],
+            overflow,
+        }
+    }
+}
+
+/// The caption of a table, which may be a plain string, or any content.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableCaption(pub Option<Html>);
+
+impl From<Html> for TableCaption {
+    fn from(caption: Html) -> Self {
+        Self(Some(caption))
+    }
+}
+
+impl From<String> for TableCaption {
+    fn from(caption: String) -> Self {
+        Self(Some(html!(caption)))
+    }
+}
+
+impl From<&str> for TableCaption {
+    fn from(caption: &str) -> Self {
+        caption.to_string().into()
+    }
+}
+
+impl<T: Into<TableCaption>> From<Option<T>> for TableCaption {
+    fn from(caption: Option<T>) -> Self {
+        caption.map(Into::into).unwrap_or_default()
+    }
+}
+
+impl IntoPropValue<TableCaption> for Html {
+    fn into_prop_value(self) -> TableCaption {
+        self.into()
+    }
+}
+
+impl IntoPropValue<TableCaption> for String {
+    fn into_prop_value(self) -> TableCaption {
+        self.into()
+    }
+}
+
+impl IntoPropValue<TableCaption> for &'static str {
+    fn into_prop_value(self) -> TableCaption {
+        self.into()
+    }
+}
+
+impl<T: Into<TableCaption>> IntoPropValue<TableCaption> for Option<T> {
+    fn into_prop_value(self) -> TableCaption {
+        self.into()
+    }
+}
 
 /// Render table entries
+///
+/// Only [`Self::render_cell`] must be implemented, all other functions have reasonable defaults.
+/// A `Vec` of entries implementing this trait can directly be used as a
+/// [`TableModel`](super::TableModel).
+///
+/// ## Example
+///
+/// ```rust
+/// use patternfly_yew::next::*;
+/// use yew::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq)]
+/// enum Column { Name, Version }
+///
+/// #[derive(PartialEq)]
+/// struct Package { name: String, version: String }
+///
+/// impl TableEntryRenderer<Column> for Package {
+///     fn render_cell(&self, context: &CellContext<'_, Column>) -> Cell {
+///         match context.column {
+///             Column::Name => html!(&self.name),
+///             Column::Version => html!(&self.version),
+///         }
+///         .into()
+///     }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///     let entries = vec![Package { name: "yew".into(), version: "0.20".into() }];
+///
+///     let header = html_nested!(
+///         <TableHeader<Column>>
+///             <TableColumn<Column> label="Name" index={Column::Name} />
+///             <TableColumn<Column> label="Version" index={Column::Version} />
+///         </TableHeader<Column>>
+///     );
+///
+///     html!(<Table<Column, Vec<Package>> {header} {entries} />)
+/// }
+/// ```
 pub trait TableEntryRenderer<C>
 where
     C: Clone + Eq + 'static,
@@ -9,6 +165,13 @@ where
     /// Render the cell for the requested column.
     fn render_cell(&self, context: &CellContext<'_, C>) -> Cell;
 
+    /// Render the cell for a column marked as [`compound`](super::TableColumnProperties::compound).
+    ///
+    /// Defaults to a regular cell, as rendered by [`Self::render_cell`].
+    fn render_compound_cell(&self, context: &CellContext<'_, C>) -> CompoundCell {
+        self.render_cell(context).into()
+    }
+
     /// Control if the details section spans the full width.
     fn is_full_width_details(&self) -> Option<bool> {
         None
@@ -16,6 +179,9 @@ where
 
     /// Render the details section.
     ///
+    /// The spans of the cells are limited to the available columns, still giving each cell at
+    /// least one column. A cell may span all remaining columns using [`Span::max`].
+    ///
     /// Defaults to not having details.
     fn render_details(&self) -> Vec<Span> {
         vec![]
@@ -24,7 +190,7 @@ where
     /// Render the row actions.
     ///
     /// Defaults to no actions.
-    fn actions(&self) -> Vec<DropdownChildVariant> {
-        vec![]
+    fn actions(&self) -> TableEntryActions {
+        TableEntryActions::default()
     }
 }
@@ -0,0 +1,168 @@
+//! Internal state of a table, shared by all rows.
+
+use super::drag::DragState;
+use std::collections::HashSet;
+use std::ops::Range;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew::virtual_dom::Key;
+
+/// The expanded entries of a table, managing the expansion state itself.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Expansion(HashSet<Key>);
+
+pub(crate) enum ExpansionAction {
+    /// Expand, or collapse, a single entry.
+    Set(Key, bool),
+    /// Replace the expanded entries.
+    Reset(HashSet<Key>),
+}
+
+impl Reducible for Expansion {
+    type Action = ExpansionAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut keys = self.0.clone();
+        match action {
+            ExpansionAction::Set(key, true) => {
+                keys.insert(key);
+            }
+            ExpansionAction::Set(key, false) => {
+                keys.remove(&key);
+            }
+            ExpansionAction::Reset(reset) => keys = reset,
+        }
+        Rc::new(Self(keys))
+    }
+}
+
+/// The scroll position and height of a virtualized table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Viewport {
+    pub scroll_top: i32,
+    pub height: i32,
+    /// The offset of the rows in the scrolled content, below the caption and the header.
+    pub offset: i32,
+}
+
+impl From<&web_sys::Element> for Viewport {
+    fn from(element: &web_sys::Element) -> Self {
+        let scroll_top = element.scroll_top();
+
+        let offset = element
+            .query_selector("tbody")
+            .ok()
+            .flatten()
+            .map(|body| {
+                let top = body.get_bounding_client_rect().top()
+                    - element.get_bounding_client_rect().top();
+                top as i32 + scroll_top
+            })
+            .unwrap_or_default();
+
+        Self {
+            scroll_top,
+            height: element.client_height(),
+            offset,
+        }
+    }
+}
+
+/// The expansion state, when the table manages it itself.
+#[derive(Clone)]
+pub(crate) struct Uncontrolled {
+    pub expansion: Rc<Expansion>,
+    pub dispatch: Callback<ExpansionAction>,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct TableState {
+    pub drag: DragState,
+    /// The expansion state, unless controlled by the model.
+    pub uncontrolled: Option<Uncontrolled>,
+    /// The rows to render, when virtualized.
+    pub window: Option<Range<usize>>,
+}
+
+impl TableState {
+    /// Evaluate the expansion state of an entry, `expanded` being the state of the model.
+    pub(crate) fn is_expanded<K>(&self, key: &K, expanded: bool) -> bool
+    where
+        K: Into<Key> + Clone,
+    {
+        match &self.uncontrolled {
+            Some(uncontrolled) => uncontrolled.expansion.0.contains(&key.clone().into()),
+            None => expanded,
+        }
+    }
+
+    /// The callback for expanding entries, updating the uncontrolled state too.
+    pub(crate) fn onexpand<K>(&self, onexpand: &Callback<(K, bool)>) -> Callback<(K, bool)>
+    where
+        K: Into<Key> + Clone + 'static,
+    {
+        match &self.uncontrolled {
+            Some(uncontrolled) => {
+                let dispatch = uncontrolled.dispatch.clone();
+                let onexpand = onexpand.clone();
+                Callback::from(move |(key, expanded): (K, bool)| {
+                    dispatch.emit(ExpansionAction::Set(key.clone().into(), expanded));
+                    onexpand.emit((key, expanded));
+                })
+            }
+            None => onexpand.clone(),
+        }
+    }
+
+    /// The callback for expanding all entries, updating the uncontrolled state too.
+    pub(crate) fn onexpandall<K>(
+        &self,
+        keys: Vec<K>,
+        onexpandall: &Callback<bool>,
+    ) -> Callback<bool>
+    where
+        K: Into<Key> + 'static,
+    {
+        match &self.uncontrolled {
+            Some(uncontrolled) => {
+                let dispatch = uncontrolled.dispatch.clone();
+                let onexpandall = onexpandall.clone();
+                let keys: HashSet<Key> = keys.into_iter().map(Into::into).collect();
+                Callback::from(move |expanded: bool| {
+                    let keys = match expanded {
+                        true => keys.clone(),
+                        false => HashSet::new(),
+                    };
+                    dispatch.emit(ExpansionAction::Reset(keys));
+                    onexpandall.emit(expanded);
+                })
+            }
+            None => onexpandall.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reduce() {
+        let expansion = Rc::new(Expansion::default());
+
+        let expansion = expansion.reduce(ExpansionAction::Set("a".into(), true));
+        let expansion = expansion.reduce(ExpansionAction::Set("b".into(), true));
+        let expansion = expansion.reduce(ExpansionAction::Set("a".into(), false));
+        assert_eq!(expansion.0, HashSet::from(["b".into()]));
+
+        let expansion = expansion.reduce(ExpansionAction::Reset(HashSet::new()));
+        assert!(expansion.0.is_empty());
+    }
+
+    #[test]
+    fn test_controlled() {
+        let state = TableState::default();
+        assert!(state.is_expanded(&"a", true));
+        assert!(!state.is_expanded(&"a", false));
+    }
+}
@@ -1,6 +1,7 @@
-use crate::AsClasses;
+use crate::{AsClasses, Breakpoint};
 use yew::prelude::*;
 
+/// Common combinations of the compact, borderless, and expandable modifiers of a table.
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
 pub enum TableMode {
     #[default]
@@ -11,6 +12,27 @@ pub enum TableMode {
     Expandable,
 }
 
+impl TableMode {
+    /// Check if the mode uses compact rows.
+    pub fn is_compact(&self) -> bool {
+        matches!(
+            self,
+            Self::Compact | Self::CompactNoBorders | Self::CompactExpandable
+        )
+    }
+
+    /// Check if the mode shows borders between rows.
+    pub fn has_borders(&self) -> bool {
+        !matches!(self, Self::CompactNoBorders)
+    }
+
+    /// Check if the mode has expandable rows.
+    pub fn is_expandable(&self) -> bool {
+        matches!(self, Self::Expandable | Self::CompactExpandable)
+    }
+}
+
+/// The breakpoint below which a table switches to grid mode.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum TableGridMode {
     Medium,
@@ -19,17 +41,31 @@ pub enum TableGridMode {
     XXLarge,
 }
 
-impl AsClasses for TableGridMode {
-    fn extend_classes(&self, classes: &mut Classes) {
+impl TableGridMode {
+    /// The breakpoint of the grid mode.
+    pub fn breakpoint(&self) -> Breakpoint {
         match self {
-            Self::Medium => classes.push(classes!("pf-m-grid-md")),
-            Self::Large => classes.push(classes!("pf-m-grid-lg")),
-            Self::XLarge => classes.push(classes!("pf-m-grid-xl")),
-            Self::XXLarge => classes.push(classes!("pf-m-grid-2xl")),
+            Self::Medium => Breakpoint::Medium,
+            Self::Large => Breakpoint::Large,
+            Self::XLarge => Breakpoint::XLarge,
+            Self::XXLarge => Breakpoint::XXLarge,
         }
     }
 }
 
+impl From<TableGridMode> for Breakpoint {
+    fn from(value: TableGridMode) -> Self {
+        value.breakpoint()
+    }
+}
+
+impl AsClasses for TableGridMode {
+    fn extend_classes(&self, classes: &mut Classes) {
+        classes.push(format!("pf-m-grid-{}", self.breakpoint()));
+    }
+}
+
+/// The text modifiers of a table [`Cell`](crate::next::Cell).
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub enum TextModifier {
     #[default]
@@ -37,8 +73,13 @@ pub enum TextModifier {
     NoWrap,
     Truncate,
     BreakWord,
+    /// Shrink the column to the width of its content.
+    FitContent,
 }
 
+/// The text modifiers of a table cell.
+pub type TableTextModifier = TextModifier;
+
 impl AsClasses for TextModifier {
     fn extend_classes(&self, classes: &mut Classes) {
         match self {
@@ -46,6 +87,7 @@ impl AsClasses for TextModifier {
             Self::NoWrap => classes.extend(classes!("pf-m-nowrap")),
             Self::Truncate => classes.extend(classes!("pf-m-truncate")),
             Self::BreakWord => classes.extend(classes!("pf-m-break-word")),
+            Self::FitContent => classes.extend(classes!("pf-m-fit-content")),
         }
     }
 }
@@ -62,3 +104,38 @@ impl AsClasses for SpanModifiers {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_text_modifier() {
+        for (modifier, class) in [
+            (TableTextModifier::Wrap, "pf-m-wrap"),
+            (TableTextModifier::NoWrap, "pf-m-nowrap"),
+            (TableTextModifier::Truncate, "pf-m-truncate"),
+            (TableTextModifier::BreakWord, "pf-m-break-word"),
+            (TableTextModifier::FitContent, "pf-m-fit-content"),
+        ] {
+            assert_eq!(modifier.as_classes(), Classes::from(class));
+        }
+    }
+
+    #[test]
+    fn test_grid_mode() {
+        assert_eq!(
+            TableGridMode::Medium.as_classes().to_string(),
+            "pf-m-grid-md"
+        );
+        assert_eq!(Breakpoint::from(TableGridMode::Medium), Breakpoint::Medium);
+
+        for (mode, class) in [
+            (TableGridMode::Large, "pf-m-grid-lg"),
+            (TableGridMode::XLarge, "pf-m-grid-xl"),
+            (TableGridMode::XXLarge, "pf-m-grid-2xl"),
+        ] {
+            assert_eq!(mode.as_classes().to_string(), class);
+        }
+    }
+}
@@ -1,12 +1,16 @@
 //! Toast notifications
-use crate::{Action, Alert, AlertGroup, AlertType};
+use crate::{Action, Alert, AlertGroup, AlertType, ButtonVariant, Icon, Id};
 use chrono::{DateTime, Utc};
 use core::cmp::Reverse;
-use gloo_timers::callback::Timeout;
-use std::{collections::BinaryHeap, time::Duration};
+use gloo_timers::callback::{Interval, Timeout};
+use std::{
+    collections::{BinaryHeap, HashMap, VecDeque},
+    rc::Rc,
+    time::Duration,
+};
 use yew::{prelude::*, virtual_dom::VChild};
 
-/// Toasts are small alerts that get shown on the top right corner of the page.
+/// Toasts are small alerts that get shown on the top right corner of the page (by default).
 ///
 /// A toast can be triggered by every component. The toast fill get sent to an agent, the Toaster.
 /// The toaster will delegate displaying the toast to an instance of a ToastViewer component.
@@ -50,6 +54,191 @@ pub struct Toast {
     pub timeout: Option<Duration>,
     pub body: Html,
     pub actions: Vec<Action>,
+    /// An optional key, which allows dismissing the toast using [`Toaster::dismiss`].
+    pub key: Option<String>,
+    /// Show the remaining time of a toast with a timeout as a progress bar.
+    pub with_progress: bool,
+    /// Called once the toast was removed, either by a timeout or by the user.
+    pub onclose: Option<Callback<ToastCloseReason>>,
+    /// Suppress this toast if a toast with the same key is already visible.
+    ///
+    /// Instead of showing the toast again, a counter gets added to the title of the visible toast.
+    /// If no key is set, the toast will always be shown.
+    pub dedupe_key: Option<String>,
+    /// The region of the [`ToastViewer`] which should show the toast.
+    ///
+    /// If unset, or the region is unknown, the toast will be shown by the default region.
+    pub region: Option<String>,
+    /// Only show the body once the user expands the toast, e.g. for long messages.
+    pub expandable: bool,
+    /// An icon replacing the icon of the type.
+    pub icon: Option<Icon>,
+}
+
+impl Toast {
+    /// Start building a new toast.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use patternfly_yew::prelude::*;
+    /// use std::time::Duration;
+    /// use yew::prelude::*;
+    ///
+    /// let toast = Toast::builder()
+    ///     .title("Upload finished")
+    ///     .r#type(AlertType::Success)
+    ///     .timeout(Duration::from_secs(5))
+    ///     .body(html!(<p>{"All files have been uploaded."}</p>))
+    ///     .action(Action::new("Show", Callback::from(|_| {})))
+    ///     .build();
+    ///
+    /// assert_eq!(toast.title, "Upload finished");
+    /// assert_eq!(toast.actions.len(), 1);
+    /// ```
+    pub fn builder() -> ToastBuilder {
+        ToastBuilder::default()
+    }
+
+    /// Assign the default timeout for the type of the toast, unless a timeout is already set.
+    ///
+    /// See [`ToastTimeouts::default`] for the default timeouts.
+    pub fn with_default_timeout(self) -> Self {
+        self.with_timeouts(&ToastTimeouts::default())
+    }
+
+    /// Assign the timeout for the type of the toast, unless a timeout is already set.
+    pub fn with_timeouts(mut self, timeouts: &ToastTimeouts) -> Self {
+        if self.timeout.is_none() {
+            self.timeout = timeouts.timeout(self.r#type);
+        }
+        self
+    }
+}
+
+/// Timeouts of toasts, by their type.
+///
+/// Applied to toasts which don't have an explicit timeout, see [`Props::default_timeouts`] and
+/// [`Toast::with_timeouts`]. A timeout of [`None`] keeps the toast until it gets closed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ToastTimeouts {
+    pub default: Option<Duration>,
+    pub info: Option<Duration>,
+    pub success: Option<Duration>,
+    pub warning: Option<Duration>,
+    pub danger: Option<Duration>,
+}
+
+impl ToastTimeouts {
+    /// Get the timeout for a type of toast.
+    pub fn timeout(&self, r#type: AlertType) -> Option<Duration> {
+        match r#type {
+            AlertType::Default => self.default,
+            AlertType::Info => self.info,
+            AlertType::Success => self.success,
+            AlertType::Warning => self.warning,
+            AlertType::Danger => self.danger,
+        }
+    }
+}
+
+impl Default for ToastTimeouts {
+    /// Remove informational toasts after 8 seconds, keep warnings and errors.
+    fn default() -> Self {
+        let timeout = Some(Duration::from_secs(8));
+        Self {
+            default: timeout,
+            info: timeout,
+            success: timeout,
+            warning: None,
+            danger: None,
+        }
+    }
+}
+
+/// A builder for [`Toast`]s, see [`Toast::builder`].
+#[derive(Clone, Debug, Default)]
+pub struct ToastBuilder {
+    toast: Toast,
+}
+
+impl ToastBuilder {
+    pub fn title(mut self, title: impl ToString) -> Self {
+        self.toast.title = title.to_string();
+        self
+    }
+
+    pub fn r#type(mut self, r#type: AlertType) -> Self {
+        self.toast.r#type = r#type;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.toast.timeout = timeout.into();
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<Html>) -> Self {
+        self.toast.body = body.into();
+        self
+    }
+
+    /// Add an action.
+    pub fn action(mut self, action: Action) -> Self {
+        self.toast.actions.push(action);
+        self
+    }
+
+    /// Replace all actions.
+    pub fn actions(mut self, actions: impl IntoIterator<Item = Action>) -> Self {
+        self.toast.actions = actions.into_iter().collect();
+        self
+    }
+
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.toast.key = Some(key.into());
+        self
+    }
+
+    pub fn with_progress(mut self, with_progress: bool) -> Self {
+        self.toast.with_progress = with_progress;
+        self
+    }
+
+    pub fn onclose(mut self, onclose: impl Into<Callback<ToastCloseReason>>) -> Self {
+        self.toast.onclose = Some(onclose.into());
+        self
+    }
+
+    pub fn dedupe_key(mut self, dedupe_key: impl Into<String>) -> Self {
+        self.toast.dedupe_key = Some(dedupe_key.into());
+        self
+    }
+
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.toast.region = Some(region.into());
+        self
+    }
+
+    pub fn expandable(mut self, expandable: bool) -> Self {
+        self.toast.expandable = expandable;
+        self
+    }
+
+    pub fn icon(mut self, icon: impl Into<Option<Icon>>) -> Self {
+        self.toast.icon = icon.into();
+        self
+    }
+
+    pub fn build(self) -> Toast {
+        self.toast
+    }
+}
+
+impl From<ToastBuilder> for Toast {
+    fn from(builder: ToastBuilder) -> Self {
+        builder.build()
+    }
 }
 
 /// Allows to convert a string into a toast by using the string as title.
@@ -61,6 +250,13 @@ impl<S: ToString> From<S> for Toast {
             body: Default::default(),
             r#type: Default::default(),
             actions: Vec::new(),
+            key: None,
+            with_progress: false,
+            onclose: None,
+            dedupe_key: None,
+            region: None,
+            expandable: false,
+            icon: None,
         }
     }
 }
@@ -71,33 +267,315 @@ pub enum ToasterRequest {
     Toast(Toast),
 }
 
-#[doc(hidden)]
+/// Actions handled by a [`ToastViewer`], also received by subscribers (see [`Toaster::subscribe`]).
+#[derive(Clone)]
 pub enum ToastAction {
-    ShowToast(Toast),
+    /// Show a toast.
+    ShowToast(Box<Toast>),
+    /// Dismiss toasts by their key.
+    Dismiss(String),
+    /// Dismiss all toasts.
+    DismissAll,
+    /// Update the content of toasts with the same key.
+    Update(Box<Toast>),
+    #[doc(hidden)]
+    Subscribe(Id, Callback<ToastAction>),
+    #[doc(hidden)]
+    Unsubscribe(Id),
+}
+
+/// A subscription to the toasts of a [`Toaster`], see [`Toaster::subscribe`].
+///
+/// Dropping the subscription unsubscribes.
+pub struct ToastSubscription {
+    id: Id,
+    regions: Rc<HashMap<Option<String>, Callback<ToastAction>>>,
+}
+
+impl Drop for ToastSubscription {
+    fn drop(&mut self) {
+        for callback in self.regions.values() {
+            callback.emit(ToastAction::Unsubscribe(self.id));
+        }
+    }
 }
 
 /// An agent for displaying toasts.
 #[derive(Clone, PartialEq)]
 pub struct Toaster {
     callback: Callback<ToastAction>,
+    /// The viewers of all regions known to this toaster, including parent viewers.
+    regions: Rc<HashMap<Option<String>, Callback<ToastAction>>>,
 }
 
 impl Toaster {
+    fn new(
+        region: Option<String>,
+        callback: Callback<ToastAction>,
+        parent: Option<&Toaster>,
+    ) -> Self {
+        let mut regions = parent
+            .map(|parent| (*parent.regions).clone())
+            .unwrap_or_default();
+        regions.insert(region, callback.clone());
+
+        Self {
+            callback,
+            regions: Rc::new(regions),
+        }
+    }
+
+    /// Find the viewer for a region, falling back to the default region and then the closest viewer.
+    fn route(&self, region: &Option<String>) -> &Callback<ToastAction> {
+        self.regions
+            .get(region)
+            .or_else(|| self.regions.get(&None))
+            .unwrap_or(&self.callback)
+    }
+
     /// Request a toast from the toast viewer.
     pub fn toast(&self, toast: Toast) {
-        self.callback.emit(ToastAction::ShowToast(toast))
+        self.route(&toast.region)
+            .emit(ToastAction::ShowToast(Box::new(toast)))
+    }
+
+    /// Observe all toasts requested in any region, e.g. for keeping a history of notifications.
+    ///
+    /// The callback receives a [`ToastAction::ShowToast`] with a clone of every [`Toast`] a
+    /// viewer receives, no matter if it gets shown, queued, or de-duplicated. The subscription
+    /// ends when the returned [`ToastSubscription`] is dropped.
+    pub fn subscribe(&self, callback: impl Into<Callback<ToastAction>>) -> ToastSubscription {
+        let id = Id::new();
+        let callback = callback.into();
+        for region in self.regions.values() {
+            region.emit(ToastAction::Subscribe(id, callback.clone()));
+        }
+        ToastSubscription {
+            id,
+            regions: self.regions.clone(),
+        }
+    }
+
+    /// Update the toasts with the same key as the provided toast, in all regions.
+    ///
+    /// The title, body, type, and actions of the visible toast are replaced in place, keeping its
+    /// position and remaining time. This allows progress-style notifications, without restarting
+    /// any animations. Queued toasts get replaced too. Toasts without a key can't be updated.
+    pub fn update(&self, toast: Toast) {
+        if toast.key.is_none() {
+            return;
+        }
+        for callback in self.regions.values() {
+            callback.emit(ToastAction::Update(Box::new(toast.clone())));
+        }
+    }
+
+    /// Dismiss all toasts, in all regions, e.g. for a "clear all notifications" button.
+    ///
+    /// This also drops all queued toasts.
+    pub fn dismiss_all(&self) {
+        for callback in self.regions.values() {
+            callback.emit(ToastAction::DismissAll);
+        }
     }
+
+    /// Dismiss all toasts with the provided key, in all regions.
+    ///
+    /// This also drops matching toasts which are still queued.
+    pub fn dismiss(&self, key: impl Into<String>) {
+        let key = key.into();
+        for callback in self.regions.values() {
+            callback.emit(ToastAction::Dismiss(key.clone()));
+        }
+    }
+}
+
+/// The reason why a toast was closed, see [`Toast::onclose`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastCloseReason {
+    /// The user closed the toast, or triggered a dismissing action.
+    User,
+    /// The timeout of the toast passed.
+    Timeout,
+    /// The toast was dismissed using the [`Toaster`].
+    Programmatic,
 }
 
+/// The position of the toasts on the screen.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ToastPosition {
+    #[default]
+    TopRight,
+    TopLeft,
+    TopCenter,
+    BottomRight,
+    BottomLeft,
+}
+
+impl ToastPosition {
+    /// The style overriding PatternFly's default (top right) position of a toast alert group.
+    ///
+    /// The offsets re-use the variables of the default position, so that themes still apply.
+    /// There is no style for the default position.
+    pub fn as_style(&self) -> Option<&'static str> {
+        match self {
+            Self::TopRight => None,
+            Self::TopLeft => Some("right: auto; left: var(--pf-c-alert-group--m-toast--Right);"),
+            Self::TopCenter => Some("right: auto; left: 50%; transform: translateX(-50%);"),
+            Self::BottomRight => {
+                Some("top: auto; bottom: var(--pf-c-alert-group--m-toast--Top);")
+            }
+            Self::BottomLeft => Some(
+                "top: auto; bottom: var(--pf-c-alert-group--m-toast--Top); right: auto; left: var(--pf-c-alert-group--m-toast--Right);",
+            ),
+        }
+    }
+
+    /// The class of a toast alert, animating it in from the edge of the position.
+    ///
+    /// The animations are defined by the [`TOAST_STYLESHEET`].
+    pub fn enter_class(&self) -> &'static str {
+        match self {
+            Self::TopRight | Self::BottomRight => "pf-m-enter-right",
+            Self::TopLeft | Self::BottomLeft => "pf-m-enter-left",
+            Self::TopCenter => "pf-m-enter-top",
+        }
+    }
+}
+
+/// The stylesheet animating toasts entering the screen, see [`ToastPosition::enter_class`].
+///
+/// PatternFly doesn't animate toasts, so the animations are opt-in: include this stylesheet once
+/// in the application, for example by adding it to the stylesheets of the application, or by
+/// rendering it in a `style` element of its root component. Without it, toasts simply appear.
+///
+/// The animation only runs when an alert gets added, as alerts are keyed by their toast. It is
+/// disabled for users preferring reduced motion.
+pub const TOAST_STYLESHEET: &str = r#"
+@keyframes pf-toast-enter-right { from { opacity: 0; transform: translateX(100%); } }
+@keyframes pf-toast-enter-left { from { opacity: 0; transform: translateX(-100%); } }
+@keyframes pf-toast-enter-top { from { opacity: 0; transform: translateY(-100%); } }
+.pf-c-alert-group.pf-m-toast .pf-c-alert.pf-m-enter-right { animation: pf-toast-enter-right var(--pf-global--TransitionDuration, 250ms) ease-out; }
+.pf-c-alert-group.pf-m-toast .pf-c-alert.pf-m-enter-left { animation: pf-toast-enter-left var(--pf-global--TransitionDuration, 250ms) ease-out; }
+.pf-c-alert-group.pf-m-toast .pf-c-alert.pf-m-enter-top { animation: pf-toast-enter-top var(--pf-global--TransitionDuration, 250ms) ease-out; }
+@media (prefers-reduced-motion: reduce) {
+  .pf-c-alert-group.pf-m-toast .pf-c-alert { animation: none; }
+}
+"#;
+
 #[derive(Clone, PartialEq, Properties)]
 pub struct Props {
     pub children: Children,
+
+    /// The position of the toasts.
+    ///
+    /// The position applies to the toasts of this viewer, and so of its region. Viewers of
+    /// different regions should use different positions, so that their toasts don't overlap.
+    /// Toasts animate in from the edge of the position, when including the [`TOAST_STYLESHEET`].
+    /// Defaults to the top right corner.
+    #[prop_or_default]
+    pub position: ToastPosition,
+
+    /// The maximum number of toasts visible at the same time.
+    ///
+    /// Additional toasts will be queued, and shown once a visible toast gets removed. Defaults to
+    /// no limit.
+    #[prop_or_default]
+    pub max_visible: Option<usize>,
+
+    /// The number of toasts shown before collapsing the others behind an overflow button.
+    ///
+    /// Unlike [`Self::max_visible`], the additional toasts are active, and can be expanded by
+    /// the user. Defaults to showing all toasts.
+    #[prop_or_default]
+    pub overflow_after: Option<usize>,
+
+    /// Timeouts for toasts without an explicit timeout, based on their type.
+    ///
+    /// Defaults to keeping toasts without a timeout until they get closed.
+    #[prop_or_default]
+    pub default_timeouts: Option<ToastTimeouts>,
+
+    /// Keep removed toasts for this duration, allowing them to transition out.
+    ///
+    /// While being removed, the alert of a toast has the class `pf-m-removing`. Defaults to
+    /// removing toasts immediately.
+    #[prop_or_default]
+    pub removal_delay: Option<Duration>,
+
+    /// Called with the ids of the displayed toasts, whenever they change.
+    ///
+    /// This reports toasts which got added, closed by the user, dismissed, or removed after
+    /// their timeout. Queued toasts are not reported until they are displayed.
+    #[prop_or_default]
+    pub onchange: Callback<Vec<usize>>,
+
+    /// The region this viewer is responsible for.
+    ///
+    /// Multiple viewers, for different regions, can be nested. A [`Toast`] will be routed to the
+    /// viewer of its region, as long as that viewer is the closest viewer of that region, or
+    /// one of its parents. Each region still expects exactly one viewer. Defaults to the default
+    /// region.
+    #[prop_or_default]
+    pub region: Option<String>,
 }
 
 pub struct ToastEntry {
     id: usize,
-    alert: VChild<Alert>,
+    toast: Toast,
+    /// The number of times this toast was requested (see [`Toast::dedupe_key`]).
+    count: usize,
     timeout: Option<DateTime<Utc>>,
+    /// The time left before the timeout, while the toast is paused.
+    remaining: Option<chrono::Duration>,
+}
+
+impl ToastEntry {
+    /// Stop the countdown, remembering the remaining time.
+    fn pause(&mut self, now: DateTime<Utc>) {
+        if let Some(timeout) = self.timeout.take() {
+            self.remaining = Some(timeout - now);
+        }
+    }
+
+    /// Continue the countdown, returning the new deadline (if any).
+    fn resume(&mut self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if let Some(remaining) = self.remaining.take() {
+            self.timeout = Some(now + remaining);
+        }
+        self.timeout
+    }
+
+    fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.timeout.map_or(false, |timeout| timeout <= now)
+    }
+
+    /// The ratio (from `1.0` to `0.0`) of the time remaining until the toast gets removed.
+    fn remaining_ratio(&self, now: DateTime<Utc>) -> Option<f64> {
+        let total = self
+            .toast
+            .timeout
+            .and_then(|timeout| chrono::Duration::from_std(timeout).ok())?;
+
+        let remaining = match (self.timeout, self.remaining) {
+            (Some(timeout), _) => timeout - now,
+            (None, Some(remaining)) => remaining,
+            (None, None) => return None,
+        };
+
+        if total.num_milliseconds() <= 0 {
+            return Some(0.0);
+        }
+
+        Some(
+            (remaining.num_milliseconds() as f64 / total.num_milliseconds() as f64).clamp(0.0, 1.0),
+        )
+    }
+
+    /// Check if the progress of the entry is currently changing.
+    fn is_progressing(&self) -> bool {
+        self.toast.with_progress && self.timeout.is_some()
+    }
 }
 
 /// A component to view toast alerts.
@@ -106,17 +584,42 @@ pub struct ToastEntry {
 /// must be on the body level of the HTML document.
 pub struct ToastViewer {
     context: Toaster,
+    /// The toaster of a parent viewer, if any.
+    parent: Option<Toaster>,
     alerts: Vec<ToastEntry>,
+    /// Toasts waiting for a free slot, in case of a limit.
+    queue: VecDeque<Toast>,
+    /// Subscribers, observing all toasts.
+    observers: Vec<(Id, Callback<ToastAction>)>,
     counter: usize,
+    /// While the pointer hovers over the toasts, all timeouts are paused.
+    paused: bool,
+    /// The user expanded the overflowing toasts.
+    overflow_expanded: bool,
+    /// The ids of the displayed toasts, as last reported to `onchange`.
+    reported: Vec<usize>,
+    /// Removed toasts, and the time of their removal, while they transition out.
+    removing: Vec<(DateTime<Utc>, ToastEntry)>,
+    /// Drops the removed toasts, once their transition is over.
+    removal: Option<Timeout>,
 
     task: Option<Timeout>,
+    /// The deadline of the scheduled cleanup.
+    scheduled: Option<DateTime<Utc>>,
     timeouts: BinaryHeap<Reverse<DateTime<Utc>>>,
+    /// Re-renders progress bars, while there are some.
+    ticker: Option<Interval>,
 }
 
 pub enum ToastViewerMsg {
     Perform(ToastAction),
     Cleanup,
+    Tick,
     Close(usize),
+    DropRemoved,
+    Pause,
+    Resume,
+    ToggleOverflow,
 }
 
 impl Component for ToastViewer {
@@ -124,33 +627,111 @@ impl Component for ToastViewer {
     type Properties = Props;
 
     fn create(ctx: &Context<Self>) -> Self {
-        let context = Toaster {
-            callback: ctx.link().callback(ToastViewerMsg::Perform),
-        };
+        let parent = ctx
+            .link()
+            .context::<Toaster>(Callback::noop())
+            .map(|(parent, _)| parent);
+        let context = Toaster::new(
+            ctx.props().region.clone(),
+            ctx.link().callback(ToastViewerMsg::Perform),
+            parent.as_ref(),
+        );
         Self {
             context,
+            parent,
             alerts: Vec::new(),
+            queue: VecDeque::new(),
+            observers: Vec::new(),
             counter: 0,
+            paused: false,
+            overflow_expanded: false,
+            reported: Vec::new(),
+            removing: Vec::new(),
+            removal: None,
             task: None,
+            scheduled: None,
             timeouts: BinaryHeap::new(),
+            ticker: None,
         }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
-        match msg {
+        let result = match msg {
             ToastViewerMsg::Perform(action) => self.perform(ctx, action),
             ToastViewerMsg::Cleanup => self.cleanup(ctx),
-            ToastViewerMsg::Close(id) => self.remove_toast(id),
+            ToastViewerMsg::Tick => true,
+            ToastViewerMsg::Close(id) => self.remove_toast(ctx, id),
+            ToastViewerMsg::DropRemoved => {
+                self.removal = None;
+                self.drop_removed(ctx, Self::now())
+            }
+            ToastViewerMsg::Pause => self.pause(),
+            ToastViewerMsg::Resume => self.resume(ctx),
+            ToastViewerMsg::ToggleOverflow => {
+                self.overflow_expanded = !self.overflow_expanded;
+                true
+            }
+        };
+
+        self.update_ticker(ctx);
+        self.update_removal(ctx);
+        self.report_change(ctx);
+
+        result
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().region != old_props.region {
+            self.context = Toaster::new(
+                ctx.props().region.clone(),
+                self.context.callback.clone(),
+                self.parent.as_ref(),
+            );
         }
+
+        // the limit might have been raised
+        self.promote(ctx);
+        self.report_change(ctx);
+        true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let context = self.context.clone();
 
+        let now = Self::now();
+
+        let onmouseenter = ctx.link().callback(|_| ToastViewerMsg::Pause);
+        let onmouseleave = ctx.link().callback(|_| ToastViewerMsg::Resume);
+
+        let (visible, overflow) = overflow(
+            self.alerts.len(),
+            ctx.props().overflow_after,
+            self.overflow_expanded,
+        );
+        let onoverflow = ctx.link().callback(|_| ToastViewerMsg::ToggleOverflow);
+
+        // removed toasts stay in place, ids are in the order of the toasts
+        let mut entries: Vec<_> = self
+            .alerts
+            .iter()
+            .take(visible)
+            .map(|entry| (entry, false))
+            .chain(self.removing.iter().map(|(_, entry)| (entry, true)))
+            .collect();
+        entries.sort_by_key(|(entry, _)| entry.id);
+
         html! {
             <ContextProvider<Toaster> {context}>
-                <AlertGroup toast=true>
-                    { for self.alerts.iter().map(|entry|entry.alert.clone()) }
+                <AlertGroup
+                    toast=true
+                    style={ctx.props().position.as_style()}
+                    {onmouseenter}
+                    {onmouseleave}
+                    {overflow}
+                    {onoverflow}
+                    aria_live={aria_live(self.alerts.iter().map(|entry| entry.toast.r#type))}
+                >
+                    { for entries.into_iter().map(|(entry, removing)| self.render_entry(ctx, entry, removing, now)) }
                 </AlertGroup>
                 { for ctx.props().children.iter() }
             </ContextProvider<Toaster>>
@@ -165,9 +746,104 @@ impl ToastViewer {
 
     fn perform(&mut self, ctx: &Context<Self>, action: ToastAction) -> bool {
         match action {
-            ToastAction::ShowToast(toast) => self.add_toast(ctx, toast),
+            ToastAction::ShowToast(toast) => {
+                self.notify(&toast);
+                self.show_toast(ctx, *toast)
+            }
+            ToastAction::Dismiss(key) => self.dismiss(ctx, &key),
+            ToastAction::DismissAll => self.dismiss_all(),
+            ToastAction::Update(toast) => self.update_toast(*toast),
+            ToastAction::Subscribe(id, callback) => {
+                self.observers.push((id, callback));
+                false
+            }
+            ToastAction::Unsubscribe(id) => {
+                self.observers.retain(|(observer, _)| *observer != id);
+                false
+            }
+        }
+    }
+
+    /// Report the ids of the displayed toasts, if they changed.
+    fn report_change(&mut self, ctx: &Context<Self>) {
+        if let Some(ids) = self.take_change() {
+            ctx.props().onchange.emit(ids);
+        }
+    }
+
+    /// Get the ids of the displayed toasts, if they changed since the last call.
+    fn take_change(&mut self) -> Option<Vec<usize>> {
+        let ids: Vec<usize> = self.alerts.iter().map(|entry| entry.id).collect();
+        match ids == self.reported {
+            true => None,
+            false => {
+                self.reported = ids.clone();
+                Some(ids)
+            }
         }
-        true
+    }
+
+    /// Send a copy of the toast to all subscribers.
+    fn notify(&self, toast: &Toast) {
+        for (_, observer) in &self.observers {
+            observer.emit(ToastAction::ShowToast(Box::new(toast.clone())));
+        }
+    }
+
+    fn has_capacity(&self, ctx: &Context<Self>) -> bool {
+        has_capacity(ctx.props().max_visible, self.alerts.len())
+    }
+
+    fn show_toast(&mut self, ctx: &Context<Self>, toast: Toast) -> bool {
+        let toast = match &ctx.props().default_timeouts {
+            Some(timeouts) => toast.with_timeouts(timeouts),
+            None => toast,
+        };
+
+        if self.dedupe(&toast) {
+            true
+        } else if self.has_capacity(ctx) {
+            self.add_toast(ctx, toast);
+            true
+        } else {
+            self.queue.push_back(toast);
+            false
+        }
+    }
+
+    /// Check if the toast is a duplicate of a visible toast, and if so, count it.
+    fn dedupe(&mut self, toast: &Toast) -> bool {
+        let key = match &toast.dedupe_key {
+            Some(key) => key,
+            None => return false,
+        };
+
+        match self
+            .alerts
+            .iter_mut()
+            .find(|entry| entry.toast.dedupe_key.as_ref() == Some(key))
+        {
+            Some(entry) => {
+                entry.count += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move toasts from the queue to the visible toasts, as long as there is capacity.
+    fn promote(&mut self, ctx: &Context<Self>) -> bool {
+        let mut changed = false;
+        while self.has_capacity(ctx) {
+            match self.queue.pop_front() {
+                Some(toast) => {
+                    self.add_toast(ctx, toast);
+                    changed = true;
+                }
+                None => break,
+            }
+        }
+        changed
     }
 
     fn add_toast(&mut self, ctx: &Context<Self>, toast: Toast) {
@@ -180,24 +856,144 @@ impl ToastViewer {
         let id = self.counter;
         self.counter += 1;
 
-        let onclose = match toast.timeout {
-            None => Some(ctx.link().callback(move |_| ToastViewerMsg::Close(id))),
-            Some(_) => None,
-        };
-
-        self.alerts.push(ToastEntry {
+        let mut entry = ToastEntry {
             id,
-            alert: html_nested! {
-                <Alert r#type={toast.r#type} title={toast.title} onclose={onclose} actions={toast.actions}>
-                    { toast.body }
-                </Alert>
-            },
+            toast,
+            count: 1,
             timeout,
-        });
+            remaining: None,
+        };
 
-        if let Some(timeout) = timeout {
+        if self.paused {
+            // don't start the countdown while the user is looking at the toasts
+            entry.pause(now);
+        } else if let Some(timeout) = timeout {
             self.schedule_cleanup(ctx, timeout);
         }
+
+        self.alerts.push(entry);
+    }
+
+    fn render_entry(
+        &self,
+        ctx: &Context<Self>,
+        entry: &ToastEntry,
+        removing: bool,
+        now: DateTime<Utc>,
+    ) -> VChild<Alert> {
+        let mut alert = match removing {
+            // already closed
+            true => render_alert(entry, Callback::noop(), now),
+            false => {
+                let id = entry.id;
+                render_alert(
+                    entry,
+                    ctx.link().callback(move |_| ToastViewerMsg::Close(id)),
+                    now,
+                )
+            }
+        };
+
+        let class = &mut Rc::make_mut(&mut alert.props).class;
+        class.push(ctx.props().position.enter_class());
+        if removing {
+            class.push("pf-m-removing");
+        }
+
+        alert
+    }
+
+    /// Start or stop the ticker, depending on the presence of progressing toasts.
+    fn update_ticker(&mut self, ctx: &Context<Self>) {
+        let needed = self.alerts.iter().any(|entry| entry.is_progressing());
+
+        match (needed, self.ticker.is_some()) {
+            (true, false) => {
+                let link = ctx.link().clone();
+                self.ticker = Some(Interval::new(PROGRESS_INTERVAL, move || {
+                    link.send_message(ToastViewerMsg::Tick);
+                }));
+            }
+            (false, true) => {
+                self.ticker = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Schedule dropping the removed toasts, once the first transition is over.
+    fn update_removal(&mut self, ctx: &Context<Self>) {
+        let delay = match removal_delay(ctx.props()) {
+            Some(delay) => delay,
+            None => {
+                self.removing.clear();
+                return;
+            }
+        };
+
+        if self.removal.is_some() {
+            return;
+        }
+
+        if let Some(removed) = self.removing.iter().map(|(removed, _)| *removed).min() {
+            let duration = (removed + delay - Self::now()).to_std().unwrap_or_default();
+            let link = ctx.link().clone();
+            self.removal = Some(Timeout::new(duration.as_millis() as u32, move || {
+                link.send_message(ToastViewerMsg::DropRemoved);
+            }));
+        }
+    }
+
+    /// Drop the removed toasts, which finished their transition.
+    fn drop_removed(&mut self, ctx: &Context<Self>, now: DateTime<Utc>) -> bool {
+        let delay = removal_delay(ctx.props()).unwrap_or_else(chrono::Duration::zero);
+        self.drop_removed_before(now - delay)
+    }
+
+    fn drop_removed_before(&mut self, time: DateTime<Utc>) -> bool {
+        let before = self.removing.len();
+        self.removing.retain(|(removed, _)| *removed > time);
+        self.removing.len() != before
+    }
+
+    fn pause(&mut self) -> bool {
+        if self.paused {
+            return false;
+        }
+
+        let now = Self::now();
+
+        self.paused = true;
+        // dropping the task cancels the timer, and the deadlines are no longer valid
+        self.task = None;
+        self.scheduled = None;
+        self.timeouts.clear();
+
+        for entry in &mut self.alerts {
+            entry.pause(now);
+        }
+
+        false
+    }
+
+    fn resume(&mut self, ctx: &Context<Self>) -> bool {
+        if !self.paused {
+            return false;
+        }
+
+        let now = Self::now();
+
+        self.paused = false;
+
+        for entry in &mut self.alerts {
+            if let Some(timeout) = entry.resume(now) {
+                self.timeouts.push(Reverse(timeout));
+            }
+        }
+
+        self.trigger_next_cleanup(ctx);
+
+        false
     }
 
     fn schedule_cleanup(&mut self, ctx: &Context<Self>, timeout: DateTime<Utc>) {
@@ -226,43 +1022,927 @@ impl ToastViewer {
                 self.task = Some(Timeout::new(duration.as_millis() as u32, move || {
                     link.send_message(ToastViewerMsg::Cleanup);
                 }));
+                self.scheduled = Some(timeout);
                 log::debug!("Scheduled cleanup: {:?}", duration);
                 break;
             }
         }
     }
 
-    fn remove_toast(&mut self, id: usize) -> bool {
-        self.retain_alert(|entry| entry.id != id)
+    fn remove_toast(&mut self, ctx: &Context<Self>, id: usize) -> bool {
+        let changed = self.close_toast(id);
+        if self.prune_timeouts() {
+            self.trigger_next_cleanup(ctx);
+        }
+        self.promote(ctx) || changed
+    }
+
+    /// Drop the deadlines of toasts which are no longer shown, e.g. after closing them manually.
+    ///
+    /// Returns `true` if the scheduled cleanup was cancelled, as it was only required for
+    /// removed toasts. In this case, the next cleanup must be triggered again.
+    fn prune_timeouts(&mut self) -> bool {
+        let pending: Vec<_> = self
+            .alerts
+            .iter()
+            .filter_map(|entry| entry.timeout)
+            .collect();
+
+        // BinaryHeap::retain requires a newer Rust version
+        self.timeouts = std::mem::take(&mut self.timeouts)
+            .into_iter()
+            .filter(|Reverse(timeout)| pending.contains(timeout))
+            .collect();
+
+        match self.scheduled {
+            Some(scheduled) if !pending.contains(&scheduled) => {
+                self.task = None;
+                self.scheduled = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Replace the toasts with the same key, keeping the state of visible toasts.
+    fn update_toast(&mut self, toast: Toast) -> bool {
+        let key = match &toast.key {
+            Some(key) => key,
+            None => return false,
+        };
+
+        for queued in self.queue.iter_mut() {
+            if queued.key.as_ref() == Some(key) {
+                *queued = toast.clone();
+            }
+        }
+
+        let mut changed = false;
+        for entry in self.alerts.iter_mut() {
+            if entry.toast.key.as_ref() == Some(key) {
+                // the timeout is already running, and only affected by the original toast
+                entry.toast = Toast {
+                    timeout: entry.toast.timeout,
+                    ..toast.clone()
+                };
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    fn dismiss(&mut self, ctx: &Context<Self>, key: &str) -> bool {
+        let changed = self.dismiss_key(key);
+        if self.prune_timeouts() {
+            self.trigger_next_cleanup(ctx);
+        }
+        self.promote(ctx) || changed
+    }
+
+    /// Remove all toasts, and stop all scheduled cleanups.
+    fn dismiss_all(&mut self) -> bool {
+        self.queue.clear();
+        self.timeouts.clear();
+        self.task = None;
+        self.scheduled = None;
+        self.retain_alert(ToastCloseReason::Programmatic, |_| false)
     }
 
     fn cleanup(&mut self, ctx: &Context<Self>) -> bool {
         let now = Self::now();
 
         self.task = None;
+        self.scheduled = None;
         self.trigger_next_cleanup(ctx);
 
-        self.retain_alert(|alert| {
-            if let Some(timeout) = alert.timeout {
-                timeout > now
-            } else {
-                true
-            }
+        let changed = self.expire(now);
+        self.promote(ctx) || changed
+    }
+
+    /// Remove the toast closed by the user.
+    fn close_toast(&mut self, id: usize) -> bool {
+        self.retain_alert(ToastCloseReason::User, |entry| entry.id != id)
+    }
+
+    /// Remove the toasts with the key, including queued ones.
+    fn dismiss_key(&mut self, key: &str) -> bool {
+        self.queue.retain(|toast| toast.key.as_deref() != Some(key));
+        self.retain_alert(ToastCloseReason::Programmatic, |entry| {
+            entry.toast.key.as_deref() != Some(key)
         })
     }
 
-    fn retain_alert<F>(&mut self, f: F) -> bool
+    /// Remove the toasts which timed out.
+    fn expire(&mut self, now: DateTime<Utc>) -> bool {
+        self.retain_alert(ToastCloseReason::Timeout, |alert| !alert.is_expired(now))
+    }
+
+    fn retain_alert<F>(&mut self, reason: ToastCloseReason, f: F) -> bool
     where
         F: Fn(&ToastEntry) -> bool,
     {
-        let before = self.alerts.len();
-        self.alerts.retain(f);
-        before != self.alerts.len()
+        let (retained, removed): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.alerts).into_iter().partition(f);
+        self.alerts = retained;
+
+        // an entry can only be removed once, so the callback will only be called once
+        for entry in &removed {
+            if let Some(onclose) = &entry.toast.onclose {
+                onclose.emit(reason);
+            }
+        }
+
+        let changed = !removed.is_empty();
+
+        // keep the entries for their transition, they get dropped by `update_removal`
+        let now = Self::now();
+        self.removing
+            .extend(removed.into_iter().map(|entry| (now, entry)));
+
+        changed
     }
 }
 
+/// The interval (in milliseconds) of updating the progress of toasts.
+const PROGRESS_INTERVAL: u32 = 250;
+
+fn render_progress(ratio: f64) -> Html {
+    let percent = (ratio * 100.0).round();
+    html!(
+        <div class="pf-c-progress pf-m-sm pf-m-singleline">
+            <div
+                class="pf-c-progress__bar"
+                role="progressbar"
+                aria-valuemin="0"
+                aria-valuemax="100"
+                aria-valuenow={percent.to_string()}
+                aria-label="Remaining time"
+            >
+                <div class="pf-c-progress__indicator" style={format!("width: {percent}%;")}></div>
+            </div>
+        </div>
+    )
+}
+
+/// Render the alert of a toast, which gets closed using `close`.
+fn render_alert(entry: &ToastEntry, close: Callback<()>, now: DateTime<Utc>) -> VChild<Alert> {
+    let toast = &entry.toast;
+
+    let actions = toast_actions(&toast.actions, &close);
+
+    let onclose = match toast.timeout {
+        None => Some(close),
+        Some(_) => None,
+    };
+
+    let title = match entry.count {
+        0 | 1 => toast.title.clone(),
+        n => format!("{} ({n})", toast.title),
+    };
+
+    let progress = match toast.with_progress {
+        true => entry.remaining_ratio(now),
+        false => None,
+    };
+
+    html_nested! {
+        <Alert key={entry.id} r#type={toast.r#type} {title} {onclose} {actions} expandable={toast.expandable} icon={toast.icon}>
+            { toast.body.clone() }
+            if let Some(progress) = progress {
+                { render_progress(progress) }
+            }
+        </Alert>
+    }
+}
+
+fn removal_delay(props: &Props) -> Option<chrono::Duration> {
+    props
+        .removal_delay
+        .and_then(|delay| chrono::Duration::from_std(delay).ok())
+}
+
+/// Let dismissing actions close the toast, after their callback was executed.
+///
+/// This works for toasts with a timeout too, which don't have a close button.
+///
+/// Actions without an explicit variant are styled like PatternFly alert actions: the first one as
+/// the primary link, the others as inline links.
+fn toast_actions(actions: &[Action], close: &Callback<()>) -> Vec<Action> {
+    actions
+        .iter()
+        .enumerate()
+        .map(|(index, action)| {
+            let variant = action.variant.unwrap_or(match index {
+                0 => ButtonVariant::Link,
+                _ => ButtonVariant::InlineLink,
+            });
+
+            let callback = match action.dismiss {
+                true => {
+                    let callback = action.callback.clone();
+                    let close = close.clone();
+                    Callback::from(move |_| {
+                        callback.emit(());
+                        close.emit(());
+                    })
+                }
+                false => action.callback.clone(),
+            };
+
+            Action {
+                callback,
+                variant: Some(variant),
+                ..action.clone()
+            }
+        })
+        .collect()
+}
+
+/// Evaluate the number of visible toasts, and the message of the overflow button, if any.
+fn overflow(
+    alerts: usize,
+    overflow_after: Option<usize>,
+    expanded: bool,
+) -> (usize, Option<String>) {
+    match overflow_after {
+        Some(after) if alerts > after => match expanded {
+            true => (alerts, Some("Show less".to_string())),
+            false => (after, Some(format!("View {} more", alerts - after))),
+        },
+        _ => (alerts, None),
+    }
+}
+
+/// The politeness of announcing the toasts, interrupting the user for warnings and errors.
+fn aria_live(types: impl IntoIterator<Item = AlertType>) -> AttrValue {
+    match types
+        .into_iter()
+        .any(|r#type| matches!(r#type, AlertType::Warning | AlertType::Danger))
+    {
+        true => "assertive".into(),
+        false => "polite".into(),
+    }
+}
+
+fn has_capacity(max_visible: Option<usize>, visible: usize) -> bool {
+    max_visible.map_or(true, |max| visible < max)
+}
+
 /// Get a [`Toaster`] context.
+///
+/// Returns [`None`] if the component is not nested inside a [`ToastViewer`]. As toasts are
+/// delivered through the context, there is no viewer which could pick up (or buffer) toasts later
+/// on, so it is up to the caller to decide how to handle this case (e.g. logging a warning).
+///
+/// ## Example
+///
+/// ```rust
+/// use patternfly_yew::prelude::*;
+/// use yew::prelude::*;
+///
+/// #[function_component(Upload)]
+/// fn upload() -> Html {
+///     let toaster = use_toaster();
+///
+///     let onclick = {
+///         let toaster = toaster.clone();
+///         Callback::from(move |_| {
+///             if let Some(toaster) = &toaster {
+///                 toaster.toast(
+///                     Toast::builder()
+///                         .title("Upload started")
+///                         .key("upload")
+///                         .build(),
+///                 );
+///             }
+///         })
+///     };
+///
+///     let oncancel = Callback::from(move |_| {
+///         if let Some(toaster) = &toaster {
+///             toaster.dismiss("upload");
+///         }
+///     });
+///
+///     html!(
+///         <>
+///             <Button label="Upload" {onclick} />
+///             <Button label="Cancel" onclick={oncancel} />
+///         </>
+///     )
+/// }
+/// ```
 #[hook]
 pub fn use_toaster() -> Option<Toaster> {
     use_context()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entry(now: DateTime<Utc>, timeout: i64) -> ToastEntry {
+        ToastEntry {
+            id: 0,
+            toast: Toast {
+                timeout: Some(Duration::from_secs(timeout as u64)),
+                with_progress: true,
+                ..Toast::from("Test")
+            },
+            count: 1,
+            timeout: Some(now + chrono::Duration::seconds(timeout)),
+            remaining: None,
+        }
+    }
+
+    #[test]
+    fn test_pause_survives_timeout() {
+        let start = Utc::now();
+        let mut entry = entry(start, 5);
+
+        // hover after two seconds
+        entry.pause(start + chrono::Duration::seconds(2));
+
+        // long after the original deadline, the toast must still be there
+        assert!(!entry.is_expired(start + chrono::Duration::seconds(60)));
+
+        // resume, the remaining three seconds start again
+        let resumed = start + chrono::Duration::seconds(60);
+        assert_eq!(
+            entry.resume(resumed),
+            Some(resumed + chrono::Duration::seconds(3))
+        );
+
+        assert!(!entry.is_expired(resumed + chrono::Duration::seconds(2)));
+        assert!(entry.is_expired(resumed + chrono::Duration::seconds(3)));
+    }
+
+    #[test]
+    fn test_remaining_ratio() {
+        let start = Utc::now();
+        let mut entry = entry(start, 10);
+
+        assert_eq!(entry.remaining_ratio(start), Some(1.0));
+        assert_eq!(
+            entry.remaining_ratio(start + chrono::Duration::seconds(4)),
+            Some(0.6)
+        );
+        assert_eq!(
+            entry.remaining_ratio(start + chrono::Duration::seconds(20)),
+            Some(0.0)
+        );
+
+        // the progress stands still while paused
+        entry.pause(start + chrono::Duration::seconds(5));
+        assert!(!entry.is_progressing());
+        assert_eq!(
+            entry.remaining_ratio(start + chrono::Duration::seconds(8)),
+            Some(0.5)
+        );
+    }
+
+    fn viewer() -> ToastViewer {
+        ToastViewer {
+            context: Toaster::new(None, Default::default(), None),
+            parent: None,
+            alerts: vec![],
+            queue: Default::default(),
+            observers: vec![],
+            counter: 0,
+            paused: false,
+            overflow_expanded: false,
+            reported: vec![],
+            removing: vec![],
+            removal: None,
+            task: None,
+            scheduled: None,
+            timeouts: Default::default(),
+            ticker: None,
+        }
+    }
+
+    #[test]
+    fn test_dedupe() {
+        let mut viewer = viewer();
+        let toast = Toast {
+            dedupe_key: Some("connection".into()),
+            ..Toast::from("Connection failed")
+        };
+
+        viewer.alerts.push(ToastEntry {
+            toast: toast.clone(),
+            ..entry(Utc::now(), 10)
+        });
+
+        assert!(viewer.dedupe(&toast));
+        assert!(viewer.dedupe(&toast));
+        assert_eq!(viewer.alerts.len(), 1);
+        assert_eq!(viewer.alerts[0].count, 3);
+
+        // no key, no de-duplication
+        assert!(!viewer.dedupe(&Toast::from("Connection failed")));
+    }
+
+    #[test]
+    fn test_route() {
+        let default = Callback::from(|_| {});
+        let chat = Callback::from(|_| {});
+
+        let parent = Toaster::new(None, default.clone(), None);
+        let toaster = Toaster::new(Some("chat".into()), chat.clone(), Some(&parent));
+
+        assert_eq!(toaster.route(&Some("chat".into())), &chat);
+        assert_eq!(toaster.route(&None), &default);
+        // unknown regions go to the default region
+        assert_eq!(toaster.route(&Some("system".into())), &default);
+
+        // a parent doesn't know about its children
+        assert_eq!(parent.route(&Some("chat".into())), &default);
+    }
+
+    #[test]
+    fn test_subscribe() {
+        use std::cell::RefCell;
+
+        let actions = Rc::new(RefCell::new(Vec::new()));
+        let viewer_callback = {
+            let actions = actions.clone();
+            Callback::from(move |action| actions.borrow_mut().push(action))
+        };
+        let toaster = Toaster::new(None, viewer_callback, None);
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let subscription = {
+            let received = received.clone();
+            toaster.subscribe(move |action| {
+                if let ToastAction::ShowToast(toast) = action {
+                    received.borrow_mut().push(toast.title);
+                }
+            })
+        };
+
+        // the viewer gets the subscription
+        let mut viewer = viewer();
+        match actions.borrow_mut().pop() {
+            Some(ToastAction::Subscribe(id, callback)) => viewer.observers.push((id, callback)),
+            _ => panic!("expected a subscription"),
+        }
+
+        viewer.notify(&Toast::from("Saved"));
+        assert_eq!(*received.borrow(), vec!["Saved".to_string()]);
+
+        drop(subscription);
+        assert!(matches!(
+            actions.borrow().last(),
+            Some(ToastAction::Unsubscribe(_))
+        ));
+    }
+
+    #[test]
+    fn test_dismissing_action() {
+        use std::cell::RefCell;
+
+        let mut viewer = viewer();
+        viewer.alerts.push(ToastEntry {
+            id: 1,
+            ..entry(Utc::now(), 10)
+        });
+
+        let log = Rc::new(RefCell::new(vec![]));
+        let action = |name: &'static str| {
+            let log = log.clone();
+            Action::new(name, Callback::from(move |_| log.borrow_mut().push(name)))
+        };
+        let closed = Rc::new(RefCell::new(vec![]));
+        let close = {
+            let closed = closed.clone();
+            Callback::from(move |_| closed.borrow_mut().push(1))
+        };
+
+        let actions = toast_actions(&[action("keep"), action("dismiss").dismissing()], &close);
+
+        actions[0].callback.emit(());
+        assert!(closed.borrow().is_empty());
+
+        // the action's callback runs first, then the toast gets closed
+        actions[1].callback.emit(());
+        assert_eq!(*log.borrow(), vec!["keep", "dismiss"]);
+        for id in closed.borrow().iter() {
+            viewer.close_toast(*id);
+        }
+        assert!(viewer.alerts.is_empty());
+    }
+
+    #[test]
+    fn test_expandable() {
+        let now = Utc::now();
+        let mut entry = entry(now, 10);
+        assert!(!render_alert(&entry, Callback::noop(), now).props.expandable);
+
+        entry.toast.expandable = true;
+        assert!(render_alert(&entry, Callback::noop(), now).props.expandable);
+    }
+
+    #[test]
+    fn test_icon() {
+        let now = Utc::now();
+        let mut entry = entry(now, 10);
+        assert_eq!(render_alert(&entry, Callback::noop(), now).props.icon, None);
+
+        entry.toast.icon = Some(Icon::InProgress);
+        assert_eq!(
+            render_alert(&entry, Callback::noop(), now).props.icon,
+            Some(Icon::InProgress)
+        );
+    }
+
+    #[test]
+    fn test_position() {
+        assert_eq!(ToastPosition::default(), ToastPosition::TopRight);
+        assert_eq!(ToastPosition::TopRight.as_style(), None);
+
+        for (position, style, class) in [
+            (ToastPosition::TopRight, None, "pf-m-enter-right"),
+            (ToastPosition::TopLeft, Some("left:"), "pf-m-enter-left"),
+            (
+                ToastPosition::TopCenter,
+                Some("left: 50%"),
+                "pf-m-enter-top",
+            ),
+            (
+                ToastPosition::BottomRight,
+                Some("bottom:"),
+                "pf-m-enter-right",
+            ),
+            (
+                ToastPosition::BottomLeft,
+                Some("bottom:"),
+                "pf-m-enter-left",
+            ),
+        ] {
+            match (position.as_style(), style) {
+                (Some(actual), Some(expected)) => {
+                    assert!(actual.contains(expected), "{position:?}: {actual}")
+                }
+                (actual, expected) => assert_eq!(actual, expected, "{position:?}"),
+            }
+            assert_eq!(position.enter_class(), class);
+
+            // each class has its animation
+            let rule = format!(".pf-c-alert.{class} {{ animation: pf-toast-{}", &class[5..]);
+            assert!(TOAST_STYLESHEET.contains(&rule), "{position:?}");
+        }
+    }
+
+    #[test]
+    fn test_severity() {
+        let now = Utc::now();
+        for (r#type, prefix) in [
+            (AlertType::Default, "Default alert:"),
+            (AlertType::Info, "Information alert:"),
+            (AlertType::Success, "Success alert:"),
+            (AlertType::Warning, "Warning alert:"),
+            (AlertType::Danger, "Danger alert:"),
+        ] {
+            let mut entry = entry(now, 10);
+            entry.toast.r#type = r#type;
+
+            // the title of the toast alert, as rendered by the alert
+            let alert = render_alert(&entry, Callback::noop(), now);
+            let title = crate::components::alert::render_title(&alert.props);
+
+            let strong = match &title {
+                Html::VTag(title) => match title.children().first() {
+                    Some(Html::VTag(strong)) => strong.clone(),
+                    other => panic!("expected the text: {other:?}"),
+                },
+                other => panic!("expected the title: {other:?}"),
+            };
+            let hidden = match strong.children().first() {
+                Some(Html::VTag(hidden)) => hidden.clone(),
+                other => panic!("expected the prefix: {other:?}"),
+            };
+
+            assert_eq!(
+                hidden
+                    .attributes
+                    .iter()
+                    .find_map(|(key, value)| (key == "class").then_some(value)),
+                Some("pf-screen-reader")
+            );
+            let text: String = hidden
+                .children()
+                .iter()
+                .map(|node| match node {
+                    Html::VText(text) => text.text.to_string(),
+                    other => panic!("expected text: {other:?}"),
+                })
+                .collect();
+            assert_eq!(text, prefix);
+        }
+    }
+
+    #[test]
+    fn test_keys() {
+        let now = Utc::now();
+        let entries: Vec<_> = (0..3)
+            .map(|id| ToastEntry {
+                id,
+                ..entry(now, 10)
+            })
+            .collect();
+
+        let keys: Vec<_> = entries
+            .iter()
+            .map(|entry| Html::from(render_alert(entry, Callback::noop(), now)))
+            .map(|alert| alert.key().map(ToString::to_string))
+            .collect();
+
+        assert_eq!(
+            keys,
+            vec![Some("0".into()), Some("1".into()), Some("2".into())]
+        );
+    }
+
+    #[test]
+    fn test_action_variant() {
+        let action = |name: &str| Action::new(name, Callback::noop());
+        let actions = toast_actions(
+            &[
+                action("primary"),
+                action("secondary"),
+                action("danger").with_variant(ButtonVariant::Danger),
+            ],
+            &Callback::noop(),
+        );
+
+        let classes: Vec<_> = actions
+            .iter()
+            .map(|action| action.variant.unwrap_or_default().as_classes())
+            .collect();
+        assert_eq!(
+            classes,
+            vec![
+                vec!["pf-m-link"],
+                vec!["pf-m-link", "pf-m-inline"],
+                vec!["pf-m-danger"]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_default_timeouts() {
+        let timeout = |r#type| {
+            Toast {
+                r#type,
+                ..Toast::from("Test")
+            }
+            .with_default_timeout()
+            .timeout
+        };
+
+        let eight = Some(Duration::from_secs(8));
+        assert_eq!(timeout(AlertType::Default), eight);
+        assert_eq!(timeout(AlertType::Info), eight);
+        assert_eq!(timeout(AlertType::Success), eight);
+        assert_eq!(timeout(AlertType::Warning), None);
+        assert_eq!(timeout(AlertType::Danger), None);
+
+        // explicit timeouts are kept
+        let toast = Toast {
+            r#type: AlertType::Danger,
+            timeout: Some(Duration::from_secs(1)),
+            ..Toast::from("Test")
+        };
+        let timeouts = ToastTimeouts {
+            danger: Some(Duration::from_secs(30)),
+            ..Default::default()
+        };
+        assert_eq!(
+            toast.with_timeouts(&timeouts).timeout,
+            Some(Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn test_update() {
+        let mut viewer = viewer();
+        viewer.alerts.push(ToastEntry {
+            id: 7,
+            toast: Toast {
+                key: Some("upload".into()),
+                timeout: Some(Duration::from_secs(10)),
+                ..Toast::from("Uploading 40%")
+            },
+            ..entry(Utc::now(), 10)
+        });
+
+        assert!(!viewer.update_toast(Toast::from("Uploading 60%")));
+        assert!(viewer.update_toast(Toast {
+            key: Some("upload".into()),
+            r#type: AlertType::Success,
+            ..Toast::from("Uploading 60%")
+        }));
+
+        let entry = &viewer.alerts[0];
+        assert_eq!(entry.id, 7);
+        assert_eq!(entry.toast.title, "Uploading 60%");
+        assert_eq!(entry.toast.r#type, AlertType::Success);
+        // the running timeout is kept
+        assert_eq!(entry.toast.timeout, Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn test_change() {
+        let mut viewer = viewer();
+        assert_eq!(viewer.take_change(), None);
+
+        viewer.alerts.push(ToastEntry {
+            id: 0,
+            ..entry(Utc::now(), 10)
+        });
+        viewer.alerts.push(ToastEntry {
+            id: 1,
+            ..entry(Utc::now(), 10)
+        });
+        assert_eq!(viewer.take_change(), Some(vec![0, 1]));
+        assert_eq!(viewer.take_change(), None);
+
+        // e.g. closed by the user
+        viewer.close_toast(0);
+        assert_eq!(viewer.take_change(), Some(vec![1]));
+
+        // e.g. cleaned up after the timeout
+        viewer.expire(Utc::now() + chrono::Duration::seconds(20));
+        assert_eq!(viewer.take_change(), Some(vec![]));
+    }
+
+    #[test]
+    fn test_prune_timeouts() {
+        let now = Utc::now();
+        let mut viewer = viewer();
+        for id in 0..3 {
+            let entry = ToastEntry {
+                id,
+                ..entry(now, 10 + id as i64)
+            };
+            viewer.timeouts.push(Reverse(entry.timeout.unwrap()));
+            viewer.alerts.push(entry);
+        }
+        // the cleanup of the first toast is scheduled
+        let Reverse(first) = viewer.timeouts.pop().unwrap();
+        viewer.scheduled = Some(first);
+
+        // closing a later toast keeps the scheduled cleanup
+        viewer.close_toast(2);
+        assert!(!viewer.prune_timeouts());
+        assert_eq!(viewer.timeouts.len(), 1);
+        assert_eq!(viewer.scheduled, Some(first));
+
+        // closing the first toast cancels its cleanup, leaving the deadline of the remaining one
+        viewer.close_toast(0);
+        assert!(viewer.prune_timeouts());
+        assert_eq!(viewer.scheduled, None);
+        assert_eq!(
+            viewer.timeouts.peek(),
+            Some(&Reverse(now + chrono::Duration::seconds(11)))
+        );
+    }
+
+    #[test]
+    fn test_dismiss_all() {
+        use std::cell::Cell;
+
+        let closed = Rc::new(Cell::new(0));
+        let mut viewer = viewer();
+        for id in 0..2 {
+            let closed = closed.clone();
+            viewer.alerts.push(ToastEntry {
+                id,
+                toast: Toast {
+                    onclose: Some(Callback::from(move |_| closed.set(closed.get() + 1))),
+                    ..Toast::from("Test")
+                },
+                ..entry(Utc::now(), 10)
+            });
+            viewer.timeouts.push(Reverse(Utc::now()));
+        }
+        viewer.queue.push_back(Toast::from("Queued"));
+
+        assert!(viewer.dismiss_all());
+        assert!(viewer.alerts.is_empty());
+        assert!(viewer.queue.is_empty());
+        assert!(viewer.timeouts.is_empty());
+        assert!(viewer.task.is_none());
+        assert_eq!(closed.get(), 2);
+
+        assert!(!viewer.dismiss_all());
+    }
+
+    #[test]
+    fn test_close_reason() {
+        use std::cell::RefCell;
+
+        let now = Utc::now();
+        let reasons = Rc::new(RefCell::new(vec![]));
+        let mut viewer = viewer();
+        for id in 0..4 {
+            let reasons = reasons.clone();
+            viewer.alerts.push(ToastEntry {
+                id,
+                toast: Toast {
+                    key: Some(format!("toast-{id}")),
+                    onclose: Some(Callback::from(move |reason| {
+                        reasons.borrow_mut().push((id, reason))
+                    })),
+                    ..Toast::from("Test")
+                },
+                ..entry(now, 10 + id as i64)
+            });
+        }
+
+        assert!(viewer.close_toast(0));
+        assert!(viewer.dismiss_key("toast-1"));
+        assert!(viewer.expire(now + chrono::Duration::seconds(12)));
+        assert!(viewer.dismiss_all());
+
+        assert_eq!(
+            *reasons.borrow(),
+            vec![
+                (0, ToastCloseReason::User),
+                (1, ToastCloseReason::Programmatic),
+                (2, ToastCloseReason::Timeout),
+                (3, ToastCloseReason::Programmatic),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_aria_live() {
+        assert_eq!(aria_live([]), "polite");
+        assert_eq!(aria_live([AlertType::Info]), "polite");
+        assert_eq!(aria_live([AlertType::Info, AlertType::Danger]), "assertive");
+        assert_eq!(aria_live([AlertType::Warning]), "assertive");
+    }
+
+    #[test]
+    fn test_removal() {
+        let mut viewer = viewer();
+        viewer.alerts.push(entry(Utc::now(), 10));
+
+        // the entry is marked as removing first
+        assert!(viewer.retain_alert(ToastCloseReason::User, |_| false));
+        assert!(viewer.alerts.is_empty());
+        assert_eq!(viewer.removing.len(), 1);
+        assert_eq!(viewer.take_change(), None);
+
+        let (removed, _) = viewer.removing[0];
+        let delay = chrono::Duration::milliseconds(300);
+
+        // and dropped after the delay
+        assert!(!viewer.drop_removed_before(removed + chrono::Duration::milliseconds(100) - delay));
+        assert_eq!(viewer.removing.len(), 1);
+        assert!(viewer.drop_removed_before(removed + chrono::Duration::milliseconds(300) - delay));
+        assert!(viewer.removing.is_empty());
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(overflow(5, None, false), (5, None));
+        // at the threshold, all toasts are shown
+        assert_eq!(overflow(3, Some(3), false), (3, None));
+        assert_eq!(
+            overflow(4, Some(3), false),
+            (3, Some("View 1 more".to_string()))
+        );
+        assert_eq!(
+            overflow(4, Some(3), true),
+            (4, Some("Show less".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_capacity() {
+        assert!(has_capacity(None, 0));
+        assert!(has_capacity(None, 100));
+
+        // only the configured number of toasts gets shown
+        let shown = (0..5).filter(|i| has_capacity(Some(3), *i)).count();
+        assert_eq!(shown, 3);
+
+        assert!(!has_capacity(Some(0), 0));
+    }
+
+    #[test]
+    fn test_pause_without_timeout() {
+        let now = Utc::now();
+        let mut entry = entry(now, 0);
+        entry.timeout = None;
+
+        entry.pause(now);
+        assert_eq!(entry.remaining, None);
+        assert_eq!(entry.resume(now), None);
+        assert!(!entry.is_expired(now + chrono::Duration::days(1)));
+    }
+}
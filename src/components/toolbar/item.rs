@@ -56,17 +56,7 @@ pub fn toolbar_item(props: &ToolbarItemProperties) -> Html {
     class.extend_from(&props.r#type);
     class.extend_from(&props.modifiers);
 
-    let style = props
-        .width
-        .iter()
-        .map(|w| format!("--pf-c-toolbar__item--Width{}: {};", w.on, w.modifier))
-        .chain(
-            props
-                .min_width
-                .iter()
-                .map(|w| format!("--pf-c-toolbar__item--MinWidth{}: {};", w.on, w.modifier)),
-        )
-        .collect::<String>();
+    let style = item_style(props);
 
     html! (
         <div {class} {style}>
@@ -74,3 +64,46 @@ pub fn toolbar_item(props: &ToolbarItemProperties) -> Html {
         </div>
     )
 }
+
+/// The style of the item, setting the widths for the different breakpoints.
+fn item_style(props: &ToolbarItemProperties) -> String {
+    props
+        .width
+        .iter()
+        .map(|w| {
+            format!(
+                "--pf-c-toolbar__item--Width{}: {};",
+                w.on.as_suffix(),
+                w.modifier
+            )
+        })
+        .chain(props.min_width.iter().map(|w| {
+            format!(
+                "--pf-c-toolbar__item--MinWidth{}: {};",
+                w.on.as_suffix(),
+                w.modifier
+            )
+        }))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::WithBreakpointExt;
+
+    #[test]
+    fn test_width() {
+        let props = yew::props!(ToolbarItemProperties {
+            width: ["10rem".to_string().all(), "20rem".to_string().md()],
+            min_width: ["5rem".to_string().all(), "8rem".to_string().md()],
+        });
+        assert_eq!(
+            item_style(&props),
+            "--pf-c-toolbar__item--Width: 10rem;\
+             --pf-c-toolbar__item--Width-on-md: 20rem;\
+             --pf-c-toolbar__item--MinWidth: 5rem;\
+             --pf-c-toolbar__item--MinWidth-on-md: 8rem;"
+        );
+    }
+}
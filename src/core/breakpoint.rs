@@ -5,11 +5,14 @@
 use crate::AsClasses;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::Deref;
+use std::str::FromStr;
 use yew::html::IntoPropValue;
 use yew::Classes;
 
 /// Breakpoint definitions
-#[derive(Copy, Clone, Debug, PartialEq)]
+///
+/// Breakpoints are ordered from the smallest width up to the largest height.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Breakpoint {
     None,
     Small,
@@ -17,10 +20,23 @@ pub enum Breakpoint {
     Large,
     XLarge,
     XXLarge,
+    /// A breakpoint on the height of the viewport, using the suffix `-on-{size}-height`.
+    Height(HeightBreakpoint),
+}
+
+/// Breakpoint definitions, for the height of the viewport.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HeightBreakpoint {
+    Small,
+    Medium,
+    Large,
+    XLarge,
+    XXLarge,
 }
 
 /// A combination of a style/variant for a specific [`Breakpoint`].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithBreakpoint<T>
 where
     T: PartialEq,
@@ -59,6 +75,11 @@ where
 /// This is typically used by components which support different variants for different breakpoints,
 /// including a default one.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct WithBreakpoints<T>(Vec<WithBreakpoint<T>>)
 where
     T: PartialEq;
@@ -78,8 +99,22 @@ impl<T> AsClasses for WithBreakpoints<T>
 where
     T: PartialEq + AsClasses,
 {
+    /// Extend the classes, ordered by breakpoint.
+    ///
+    /// If there are multiple variants for the same breakpoint, only the last one is used.
     fn extend_classes(&self, classes: &mut Classes) {
-        AsClasses::extend_classes(&self.0, classes)
+        let mut variants: Vec<&WithBreakpoint<T>> = Vec::with_capacity(self.0.len());
+        for variant in &self.0 {
+            match variants.iter_mut().find(|v| v.on == variant.on) {
+                Some(existing) => *existing = variant,
+                None => variants.push(variant),
+            }
+        }
+        variants.sort_by_key(|variant| variant.on);
+
+        for variant in variants {
+            variant.extend_classes(classes);
+        }
     }
 }
 
@@ -93,11 +128,70 @@ where
             self.modifier
                 .as_classes()
                 .into_iter()
-                .map(|c| format!("{}{}", c, self.on)),
+                .map(|c| format!("{}{}", c, self.on.as_suffix())),
         )
     }
 }
 
+impl<T> WithBreakpoints<T>
+where
+    T: PartialEq,
+{
+    /// Create a new, empty, set of variants.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a variant, for a specific breakpoint.
+    pub fn push(&mut self, modifier: impl Into<WithBreakpoint<T>>) {
+        self.0.push(modifier.into());
+    }
+
+    /// Add a variant, for a specific breakpoint, returning the result.
+    ///
+    /// ```rust
+    /// use patternfly_yew::prelude::*;
+    ///
+    /// let cols: WithBreakpoints<u16> = WithBreakpoints::new().with(4.all()).with(8.lg());
+    /// assert_eq!(cols.len(), 2);
+    /// ```
+    pub fn with(mut self, modifier: impl Into<WithBreakpoint<T>>) -> Self {
+        self.push(modifier);
+        self
+    }
+
+    /// Get the variant in effect at a breakpoint.
+    ///
+    /// Variants cascade to larger breakpoints, unless overridden by a variant of a larger
+    /// breakpoint. Variants for the height of the viewport only cascade to larger height
+    /// breakpoints, while [`Breakpoint::None`] applies to all breakpoints. If there are multiple
+    /// variants for the same breakpoint, the last one is used.
+    ///
+    /// ```rust
+    /// use patternfly_yew::prelude::*;
+    ///
+    /// let cols: WithBreakpoints<u16> = [4.all(), 8.md()].into();
+    /// assert_eq!(cols.resolve(Breakpoint::Small), Some(&4));
+    /// assert_eq!(cols.resolve(Breakpoint::XLarge), Some(&8));
+    /// ```
+    pub fn resolve(&self, breakpoint: Breakpoint) -> Option<&T> {
+        self.0
+            .iter()
+            .filter(|variant| variant.on.cascades_to(breakpoint))
+            .max_by_key(|variant| variant.on)
+            .map(|variant| &variant.modifier)
+    }
+}
+
+impl<T> FromIterator<WithBreakpoint<T>> for WithBreakpoints<T>
+where
+    T: PartialEq,
+{
+    fn from_iter<I: IntoIterator<Item = WithBreakpoint<T>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
 impl<T> WithBreakpoints<T>
 where
     T: Clone + PartialEq,
@@ -156,17 +250,129 @@ where
     }
 }
 
-impl Display for Breakpoint {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl Breakpoint {
+    /// The name of the breakpoint, as used by PatternFly (e.g. `md`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Breakpoint::None => "none",
+            Breakpoint::Small => "sm",
+            Breakpoint::Medium => "md",
+            Breakpoint::Large => "lg",
+            Breakpoint::XLarge => "xl",
+            Breakpoint::XXLarge => "2xl",
+            Breakpoint::Height(HeightBreakpoint::Small) => "sm-height",
+            Breakpoint::Height(HeightBreakpoint::Medium) => "md-height",
+            Breakpoint::Height(HeightBreakpoint::Large) => "lg-height",
+            Breakpoint::Height(HeightBreakpoint::XLarge) => "xl-height",
+            Breakpoint::Height(HeightBreakpoint::XXLarge) => "2xl-height",
+        }
+    }
+
+    /// Check if a viewport of the given size, in pixels, is at least the size of the breakpoint.
+    pub fn matches(&self, width: f64, height: f64) -> bool {
         match self {
-            Breakpoint::None => f.write_str(""),
-            Breakpoint::Small => f.write_str("-on-sm"),
-            Breakpoint::Medium => f.write_str("-on-md"),
-            Breakpoint::Large => f.write_str("-on-lg"),
-            Breakpoint::XLarge => f.write_str("-on-xl"),
-            Breakpoint::XXLarge => f.write_str("-on-2xl"),
+            Breakpoint::None => true,
+            Breakpoint::Small => width >= 576.0,
+            Breakpoint::Medium => width >= 768.0,
+            Breakpoint::Large => width >= 992.0,
+            Breakpoint::XLarge => width >= 1200.0,
+            Breakpoint::XXLarge => width >= 1450.0,
+            Breakpoint::Height(HeightBreakpoint::Small) => height >= 0.0,
+            Breakpoint::Height(HeightBreakpoint::Medium) => height >= 640.0,
+            Breakpoint::Height(HeightBreakpoint::Large) => height >= 768.0,
+            Breakpoint::Height(HeightBreakpoint::XLarge) => height >= 960.0,
+            Breakpoint::Height(HeightBreakpoint::XXLarge) => height >= 1280.0,
+        }
+    }
+
+    /// Check if a variant for this breakpoint applies at the other breakpoint.
+    fn cascades_to(&self, other: Breakpoint) -> bool {
+        match (*self, other) {
+            (Breakpoint::None, _) => true,
+            (Breakpoint::Height(this), Breakpoint::Height(other)) => this <= other,
+            (Breakpoint::Height(_), _) | (_, Breakpoint::Height(_)) => false,
+            (this, other) => this <= other,
         }
     }
+
+    /// The suffix of modifier classes for this breakpoint (e.g. `-on-md`).
+    pub fn as_suffix(&self) -> &'static str {
+        match self {
+            Breakpoint::None => "",
+            Breakpoint::Small => "-on-sm",
+            Breakpoint::Medium => "-on-md",
+            Breakpoint::Large => "-on-lg",
+            Breakpoint::XLarge => "-on-xl",
+            Breakpoint::XXLarge => "-on-2xl",
+            Breakpoint::Height(HeightBreakpoint::Small) => "-on-sm-height",
+            Breakpoint::Height(HeightBreakpoint::Medium) => "-on-md-height",
+            Breakpoint::Height(HeightBreakpoint::Large) => "-on-lg-height",
+            Breakpoint::Height(HeightBreakpoint::XLarge) => "-on-xl-height",
+            Breakpoint::Height(HeightBreakpoint::XXLarge) => "-on-2xl-height",
+        }
+    }
+}
+
+impl Display for Breakpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error when parsing an unknown [`Breakpoint`] name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseBreakpointError(pub String);
+
+impl Display for ParseBreakpointError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown breakpoint: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for ParseBreakpointError {}
+
+impl FromStr for Breakpoint {
+    type Err = ParseBreakpointError;
+
+    /// Parse the name of a breakpoint, the empty string being [`Breakpoint::None`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "" | "none" => Self::None,
+            "sm" => Self::Small,
+            "md" => Self::Medium,
+            "lg" => Self::Large,
+            "xl" => Self::XLarge,
+            "2xl" => Self::XXLarge,
+            "sm-height" => Self::Height(HeightBreakpoint::Small),
+            "md-height" => Self::Height(HeightBreakpoint::Medium),
+            "lg-height" => Self::Height(HeightBreakpoint::Large),
+            "xl-height" => Self::Height(HeightBreakpoint::XLarge),
+            "2xl-height" => Self::Height(HeightBreakpoint::XXLarge),
+            _ => return Err(ParseBreakpointError(s.to_string())),
+        })
+    }
+}
+
+/// Serializes as the name of the breakpoint, e.g. `"md"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Breakpoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Breakpoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let name = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
 }
 
 /// Helps creating [`WithBreakpoint`] instances.
@@ -218,6 +424,30 @@ where
     fn xxl(self) -> WithBreakpoint<T> {
         self.on(Breakpoint::XXLarge)
     }
+
+    fn on_height(self, breakpoint: HeightBreakpoint) -> WithBreakpoint<T> {
+        self.on(Breakpoint::Height(breakpoint))
+    }
+
+    fn sm_height(self) -> WithBreakpoint<T> {
+        self.on_height(HeightBreakpoint::Small)
+    }
+
+    fn md_height(self) -> WithBreakpoint<T> {
+        self.on_height(HeightBreakpoint::Medium)
+    }
+
+    fn lg_height(self) -> WithBreakpoint<T> {
+        self.on_height(HeightBreakpoint::Large)
+    }
+
+    fn xl_height(self) -> WithBreakpoint<T> {
+        self.on_height(HeightBreakpoint::XLarge)
+    }
+
+    fn xxl_height(self) -> WithBreakpoint<T> {
+        self.on_height(HeightBreakpoint::XXLarge)
+    }
 }
 
 impl<T> WithBreakpointExt<T> for T
@@ -396,6 +626,128 @@ mod test {
         assert_eq!(prop.as_classes(), Classes::from("one two-on-2xl"));
     }
 
+    #[test]
+    fn test_builder() {
+        let prop = WithBreakpoints::new()
+            .with("one".to_string())
+            .with("two".to_string().md());
+        assert_eq!(prop.as_classes(), Classes::from("one two-on-md"));
+
+        let prop: WithBreakpoints<String> = prop.into_iter().rev().collect();
+        assert_eq!(prop[0], "two".to_string().md());
+    }
+
+    #[test]
+    fn test_normalized() {
+        let prop: WithBreakpoints<String> = [
+            "lg".to_string().lg(),
+            "height".to_string().sm_height(),
+            "first".to_string().sm(),
+            "all".to_string().all(),
+            "second".to_string().sm(),
+        ]
+        .into();
+        assert_eq!(
+            prop.as_classes().to_string(),
+            "all second-on-sm lg-on-lg height-on-sm-height"
+        );
+    }
+
+    #[test]
+    fn test_height() {
+        let prop: WithBreakpoints<String> = [
+            "one".to_string().md(),
+            "two".to_string().sm_height(),
+            "three".to_string().xxl_height(),
+        ]
+        .into();
+        assert_eq!(
+            prop.as_classes(),
+            Classes::from("one-on-md two-on-sm-height three-on-2xl-height")
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        let prop: WithBreakpoints<u16> = WithBreakpoints::new();
+        assert_eq!(prop.resolve(Breakpoint::Large), None);
+
+        // no default, only starting at md
+        let prop: WithBreakpoints<u16> = [4.md(), 8.xl()].into();
+        assert_eq!(prop.resolve(Breakpoint::None), None);
+        assert_eq!(prop.resolve(Breakpoint::Small), None);
+        assert_eq!(prop.resolve(Breakpoint::Medium), Some(&4));
+        assert_eq!(prop.resolve(Breakpoint::Large), Some(&4));
+        assert_eq!(prop.resolve(Breakpoint::XLarge), Some(&8));
+        assert_eq!(prop.resolve(Breakpoint::XXLarge), Some(&8));
+
+        // order doesn't matter, the last one wins for the same breakpoint
+        let prop: WithBreakpoints<u16> = [6.lg(), 1.all(), 2.all()].into();
+        assert_eq!(prop.resolve(Breakpoint::Small), Some(&2));
+        assert_eq!(prop.resolve(Breakpoint::Large), Some(&6));
+
+        // height breakpoints cascade on their own
+        let prop: WithBreakpoints<u16> = [1.all(), 2.lg(), 3.md_height()].into();
+        assert_eq!(prop.resolve(Breakpoint::XLarge), Some(&2));
+        assert_eq!(
+            prop.resolve(Breakpoint::Height(HeightBreakpoint::Small)),
+            Some(&1)
+        );
+        assert_eq!(
+            prop.resolve(Breakpoint::Height(HeightBreakpoint::XLarge)),
+            Some(&3)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use serde_json::json;
+
+        let value = serde_json::to_value(Breakpoint::Medium).unwrap();
+        assert_eq!(value, json!("md"));
+        assert_eq!(
+            serde_json::from_value::<Breakpoint>(json!("2xl-height")).unwrap(),
+            Breakpoint::Height(HeightBreakpoint::XXLarge)
+        );
+        assert!(serde_json::from_value::<Breakpoint>(json!("huge")).is_err());
+
+        let variants = WithBreakpoints::<u16>::new().with(4.all()).with(8.lg());
+        let value = serde_json::to_value(&variants).unwrap();
+        assert_eq!(
+            value,
+            json!([{"modifier": 4, "on": "none"}, {"modifier": 8, "on": "lg"}])
+        );
+        assert_eq!(
+            serde_json::from_value::<WithBreakpoints<u16>>(value).unwrap(),
+            variants
+        );
+    }
+
+    #[test]
+    fn test_parse_breakpoint() {
+        for breakpoint in [
+            Breakpoint::None,
+            Breakpoint::Small,
+            Breakpoint::Medium,
+            Breakpoint::Large,
+            Breakpoint::XLarge,
+            Breakpoint::XXLarge,
+            Breakpoint::Height(HeightBreakpoint::Small),
+            Breakpoint::Height(HeightBreakpoint::XXLarge),
+        ] {
+            assert_eq!(breakpoint.to_string().parse(), Ok(breakpoint));
+        }
+
+        assert_eq!(Breakpoint::Medium.to_string(), "md");
+        assert_eq!(Breakpoint::Medium.as_suffix(), "-on-md");
+        assert_eq!("".parse(), Ok(Breakpoint::None));
+        assert_eq!(
+            "huge".parse::<Breakpoint>(),
+            Err(ParseBreakpointError("huge".to_string()))
+        );
+    }
+
     #[test]
     fn test_some_variant() {
         let prop: WithBreakpoints<MockVariant> = [MockVariant::Foo.all()].into();
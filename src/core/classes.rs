@@ -67,12 +67,30 @@ where
     T: AsClasses,
 {
     fn extend_classes(&self, classes: &mut Classes) {
-        for i in self {
-            classes.extend(i.as_classes());
+        self.as_slice().extend_classes(classes)
+    }
+}
+
+impl<T> AsClasses for &[T]
+where
+    T: AsClasses,
+{
+    fn extend_classes(&self, classes: &mut Classes) {
+        for i in self.iter() {
+            i.extend_classes(classes);
         }
     }
 }
 
+impl<T, const N: usize> AsClasses for [T; N]
+where
+    T: AsClasses,
+{
+    fn extend_classes(&self, classes: &mut Classes) {
+        self.as_slice().extend_classes(classes)
+    }
+}
+
 /// Allow extending a set of classes
 pub trait ExtendClasses<A: AsClasses> {
     /// Extend a set of classes with a value implementing [`AsClasses`].
@@ -84,3 +102,28 @@ impl<A: AsClasses> ExtendClasses<A> for Classes {
         from.extend_classes(self)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::{Visibility, WithBreakpointExt};
+
+    #[test]
+    fn test_option() {
+        assert_eq!(None::<&str>.as_classes(), Classes::new());
+        assert_eq!(Some("pf-m-foo").as_classes(), Classes::from("pf-m-foo"));
+    }
+
+    #[test]
+    fn test_slice() {
+        let modifiers: &[Option<&str>] = &[Some("pf-m-foo"), None, Some("pf-m-bar")];
+        assert_eq!(modifiers.as_classes().to_string(), "pf-m-foo pf-m-bar");
+
+        let mut classes = Classes::from("pf-c-foo");
+        classes.extend_from(&[Visibility::Hidden.all(), Visibility::Visible.md()]);
+        assert_eq!(
+            classes.to_string(),
+            "pf-c-foo pf-m-hidden pf-m-visible-on-md"
+        );
+    }
+}
@@ -35,6 +35,7 @@ pub use crate::components::input_group::*;
 pub use crate::components::label::*;
 pub use crate::components::list::*;
 pub use crate::components::login_page::*;
+pub use crate::components::masthead::*;
 pub use crate::components::modal::*;
 pub use crate::components::nav::*;
 pub use crate::components::page::*;
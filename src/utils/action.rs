@@ -1,12 +1,39 @@
+use crate::ButtonVariant;
 use yew::prelude::*;
 
 /// Definition of an action.
+///
+/// Create actions using [`Action::new`] and the builder methods. Struct literals need to be
+/// completed using `..Default::default()`, as fields may be added:
+///
+/// ```rust
+/// use patternfly_yew::prelude::*;
+/// use yew::prelude::*;
+///
+/// let action = Action {
+///     label: "Retry".into(),
+///     callback: Callback::from(|_| log::info!("Retrying")),
+///     dismiss: true,
+///     variant: Some(ButtonVariant::Primary),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     action,
+///     Action::new("Retry", action.callback.clone())
+///         .dismissing()
+///         .with_variant(ButtonVariant::Primary)
+/// );
+/// ```
 #[derive(Clone, PartialEq, Default, Debug)]
 pub struct Action {
     /// The label for the end user
     pub label: String,
     /// The callback to execute when the action is triggered
     pub callback: Callback<()>,
+    /// Close the containing element, e.g. a toast, after the callback was executed
+    pub dismiss: bool,
+    /// The style of the action's button, defaults to the style of the containing element
+    pub variant: Option<ButtonVariant>,
 }
 
 impl Action {
@@ -17,8 +44,22 @@ impl Action {
         Self {
             label: label.to_string(),
             callback,
+            dismiss: false,
+            variant: None,
         }
     }
+
+    /// Close the containing element, e.g. a toast, after the action was triggered.
+    pub fn dismissing(mut self) -> Self {
+        self.dismiss = true;
+        self
+    }
+
+    /// Render the action using a specific button variant.
+    pub fn with_variant(mut self, variant: ButtonVariant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
 }
 
 /// Allows converting something into an [`Action`] by providing a label.
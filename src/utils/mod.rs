@@ -7,6 +7,7 @@ mod html;
 mod popper;
 mod props;
 mod raw;
+mod visible;
 
 pub use action::*;
 pub use context::*;
@@ -15,6 +16,7 @@ pub use html::*;
 pub use popper::*;
 pub use props::*;
 pub use raw::*;
+pub use visible::*;
 
 use std::fmt::{Debug, Display, Formatter};
 use web_sys::KeyboardEvent;
@@ -0,0 +1,93 @@
+use crate::{AsClasses, ExtendClasses, Visibility, WithBreakpoints};
+use yew::prelude::*;
+
+/// Properties for [`Visible`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct VisibleProperties {
+    #[prop_or_default]
+    pub children: Children,
+    /// The visibility of the content, for the different breakpoints.
+    #[prop_or_default]
+    pub visibility: WithBreakpoints<Visibility>,
+}
+
+/// Show or hide content, depending on the size of the screen.
+///
+/// This wraps the children in an element, using PatternFly's display utility classes.
+///
+/// ## Properties
+///
+/// Defined by [`VisibleProperties`].
+///
+/// ## Example
+///
+/// Content only visible on large screens:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///     html!(
+///         <Visible visibility={[Visibility::Hidden.all(), Visibility::Visible.lg()]}>
+///             { "Only on large screens" }
+///         </Visible>
+///     )
+/// }
+/// ```
+#[function_component(Visible)]
+pub fn visible(props: &VisibleProperties) -> Html {
+    html!(
+        <div class={visible_classes(props)}>
+            { for props.children.iter() }
+        </div>
+    )
+}
+
+/// A [`Visibility`], expressed as display utility class.
+#[derive(Clone, Copy, PartialEq)]
+struct DisplayUtility(Visibility);
+
+impl AsClasses for DisplayUtility {
+    fn extend_classes(&self, classes: &mut Classes) {
+        match self.0 {
+            Visibility::Hidden => classes.push("pf-u-display-none"),
+            Visibility::Visible => classes.push("pf-u-display-block"),
+        }
+    }
+}
+
+fn visible_classes(props: &VisibleProperties) -> Classes {
+    let mut class = Classes::new();
+    class.extend_from(&props.visibility.mapped(DisplayUtility));
+    class
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::WithBreakpointExt;
+
+    #[test]
+    fn test_visible_on_lg() {
+        let props = yew::props!(VisibleProperties {
+            visibility: [Visibility::Hidden.all(), Visibility::Visible.lg()],
+        });
+        assert_eq!(
+            visible_classes(&props).to_string(),
+            "pf-u-display-none pf-u-display-block-on-lg"
+        );
+    }
+
+    #[test]
+    fn test_hidden_on_md() {
+        let props = yew::props!(VisibleProperties {
+            visibility: Visibility::Hidden.md(),
+        });
+        assert_eq!(
+            visible_classes(&props).to_string(),
+            "pf-u-display-none-on-md"
+        );
+    }
+}